@@ -0,0 +1,159 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! Const functions for generating signed integer sine waves as arrays.
+//!
+//! This is a companion to [sine_macro](https://docs.rs/sine_macro) for code where the frequency,
+//! sampling rate or the length of the array are not known until the compiler resolves them, such
+//! as const generic parameters. [`sine_table`] and [`sample`] use integer arithmetic only, so they
+//! work in const context and on targets without a floating point unit, and they produce the same
+//! values as `sine_wave!` does with the default type of [`i16`]. [`sin_cos_turn`] gives the
+//! underlying [`f64`] values for other types.
+//!
+//! # Example
+//! ```rust
+//! use sine_macro_const::{period, sine_table};
+//!
+//! const FREQUENCY: u32 = 441;
+//! const RATE: u32 = 44_100;
+//!
+//! // One period of a sine wave, i.e. the same as `sine_wave!(frequency: 441, rate: 44_100)`
+//! const WAVE: [i16; period(FREQUENCY, RATE)] = sine_table(FREQUENCY, RATE);
+//! assert_eq!(WAVE.len(), 100);
+//! assert_eq!(WAVE[25], i16::MAX);
+//!
+//! // Generic code can use any length, which repeats the period like `len` does
+//! struct Beeper<const N: usize> {
+//!     wave: [i16; N],
+//! }
+//!
+//! impl<const N: usize> Beeper<N> {
+//!     const fn new(frequency: u32, rate: u32) -> Self {
+//!         Self {
+//!             wave: sine_table(frequency, rate),
+//!         }
+//!     }
+//! }
+//!
+//! let beeper = Beeper::<300>::new(FREQUENCY, RATE);
+//! assert_eq!(beeper.wave[..100], WAVE);
+//! ```
+
+#![no_std]
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+
+/// Number of fraction bits in the fixed point numbers that the sine is calculated with.
+const FRACTION: u32 = 62;
+
+/// One as a fixed point number.
+const ONE: i128 = 1 << FRACTION;
+
+/// π/2 as a fixed point number.
+const FRAC_PI_2: i128 = 7_244_019_458_077_122_842;
+
+/// Number of terms in the Taylor series, which is enough for the precision of the fixed point
+/// numbers in range `0..=π/4`.
+const TERMS: i128 = 10;
+
+/// Multiplies two fixed point numbers.
+const fn mul(a: i128, b: i128) -> i128 {
+    (a * b) >> FRACTION
+}
+
+/// Returns the sine and cosine of `x` in range `0..=π/4` as fixed point numbers.
+const fn sin_cos(x: i128) -> (i128, i128) {
+    let square = mul(x, x);
+    let mut sin = ONE;
+    let mut cos = ONE;
+    let mut k = TERMS;
+    while k > 0 {
+        sin = ONE - mul(square, sin) / (2 * k * (2 * k + 1));
+        cos = ONE - mul(square, cos) / ((2 * k - 1) * 2 * k);
+        k -= 1;
+    }
+    (mul(x, sin), cos)
+}
+
+/// Returns the number of samples in a period of a wave of `frequency` at sampling `rate`.
+///
+/// This is rounded down like with `sine_wave!`, so the frequency of the wave is slightly higher
+/// than `frequency` when `rate` is not a multiple of it.
+///
+/// # Panics
+/// Panics if `frequency` is zero or higher than `rate`.
+pub const fn period(frequency: u32, rate: u32) -> usize {
+    assert!(frequency > 0, "`frequency` must be positive");
+    assert!(frequency <= rate, "`frequency` should be less than `rate`");
+    (rate / frequency) as usize
+}
+
+/// Returns the sine and cosine of `numerator / denominator` of a full turn as fixed point numbers.
+const fn sin_cos_fixed(numerator: u64, denominator: u64) -> (i128, i128) {
+    // The phase as a fraction of a full turn, which is exact at every quarter of the turn
+    let turn = (((numerator % denominator) as u128) << 64) / denominator as u128;
+    let quadrant = (turn >> FRACTION) as u8;
+    let fraction = (turn & (ONE as u128 - 1)) as i128;
+    // Only the first half of the quadrant is calculated directly
+    let (sin, cos) = if fraction <= ONE / 2 {
+        sin_cos(mul(fraction, FRAC_PI_2))
+    } else {
+        let (sin, cos) = sin_cos(mul(ONE - fraction, FRAC_PI_2));
+        (cos, sin)
+    };
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Returns the sine and cosine of `numerator / denominator` of a full turn.
+///
+/// The values are calculated with the same fixed point numbers as [`sample`], so the values at
+/// every quarter of a turn are exact, e.g. the sine is exactly zero at half of a turn.
+/// `sine_wave!` calculates all of its values with this, so this gives the same [`f64`] values in
+/// const context.
+///
+/// # Panics
+/// Panics if `denominator` is zero.
+pub const fn sin_cos_turn(numerator: u64, denominator: u64) -> (f64, f64) {
+    assert!(denominator > 0, "`denominator` must be positive");
+    let (sin, cos) = sin_cos_fixed(numerator, denominator);
+    (sin as f64 / ONE as f64, cos as f64 / ONE as f64)
+}
+
+/// Returns the sample at `index` of a sine wave whose period is `period` samples.
+///
+/// The value is scaled to range `-i16::MAX..=i16::MAX` and truncated towards zero like with
+/// `sine_wave!`. `index` can also be beyond the first period.
+///
+/// # Panics
+/// Panics if `period` is zero.
+pub const fn sample(index: usize, period: usize) -> i16 {
+    assert!(period > 0, "`period` must be positive");
+    let (sin, _) = sin_cos_fixed(index as u64, period as u64);
+    (sin * i16::MAX as i128 / ONE) as i16
+}
+
+/// Returns `N` samples of a sine wave of `frequency` at sampling `rate`.
+///
+/// The array starts with one period of the wave like `sine_wave!(frequency: .., rate: ..)` and
+/// repeats it if `N` is larger than [`period`], or cuts it short like `len` does if `N` is smaller
+/// than that.
+///
+/// # Panics
+/// Panics if `frequency` is zero or higher than `rate`.
+pub const fn sine_table<const N: usize>(frequency: u32, rate: u32) -> [i16; N] {
+    let period = period(frequency, rate);
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = sample(i, period);
+        i += 1;
+    }
+    values
+}
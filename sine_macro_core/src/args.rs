@@ -0,0 +1,371 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! Parsing of `sine_wave!` arguments from text, e.g. for command-line tools.
+
+use crate::codegen::SampleType;
+use crate::{Error, FullScale, Modulation, Rounding, WaveSpec};
+use std::num::{NonZeroU32, NonZeroUsize, ParseIntError};
+use std::str::FromStr;
+
+/// Arguments of `sine_wave!` in the order they are listed in [`Error::InvalidIdentifier`].
+pub const ARGUMENTS: &[&str] = &[
+    "frequency",
+    "rate",
+    "len",
+    "repeats",
+    "skip",
+    "type",
+    "modulation",
+    "modulator",
+    "depth",
+    "deviation",
+    "rounding",
+    "dither",
+    "full_scale",
+    "quadrature",
+    "phases",
+    "shape",
+    "layout",
+    "pwm_top",
+    "dead_time",
+    "pdm",
+    "bit_order",
+    "bytes",
+    "justify",
+    "storage",
+    "wrap",
+];
+
+/// Arguments of `sine_wave!` that are only supported by the macro.
+pub const MACRO_ONLY: &[&str] = &[
+    "quadrature",
+    "phases",
+    "shape",
+    "layout",
+    "pwm_top",
+    "dead_time",
+    "pdm",
+    "bit_order",
+    "bytes",
+    "justify",
+    "storage",
+    "wrap",
+];
+
+/// Values of `modulation`, amplitude and frequency modulation.
+pub const MODULATIONS: &[&str] = &["am", "fm"];
+
+/// Values of `rounding` in the order of the variants of [`Rounding`].
+pub const ROUNDINGS: &[&str] = &["truncate", "nearest", "floor", "ceil"];
+
+/// Values of `full_scale` in the order of the variants of [`FullScale`].
+pub const FULL_SCALES: &[&str] = &["symmetric", "asymmetric"];
+
+/// The types of `sine_wave!` as listed in [`Error::InvalidType`].
+///
+/// The types behind features are listed with their features whether they are enabled or not, so
+/// that the message is the same with all features.
+pub const TYPES: &str = "`i8`, `i16`, `i24`, `i32`, `i64`, `i128`, `u8`, `u16`, `u32`, `f32`, `f64`, `f16` with the `half` feature and signed fixed point types like `I1F15` with the `fixed` feature";
+
+/// Values of `type` that are supported when parsing from text.
+const SAMPLE_TYPES: &[&str] = &["i8", "i16", "i32", "i64", "f32", "f64"];
+
+/// Values of `type` other than fixed point types that are supported only by `sine_wave!`.
+const MACRO_TYPES: &[&str] = &["i24", "i128", "u8", "u16", "u32", "f16"];
+
+/// Returns true if `ty` is named like a fixed point type, e.g. `I1F15`.
+fn is_fixed(ty: &str) -> bool {
+    let Some((integer, fraction)) = ty.strip_prefix('I').and_then(|ty| ty.split_once('F')) else {
+        return false;
+    };
+    [integer, fraction]
+        .iter()
+        .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// A wave and the type of its values parsed from the same arguments as `sine_wave!` takes.
+///
+/// Only the arguments that [`WaveSpec`] has and `type` are supported, and `type` must be one of
+/// the types of [`SampleType`]. The errors are the same as the macro gives for the same input,
+/// except that integers must be literals instead of arithmetic expressions.
+///
+/// # Example
+/// ```rust
+/// use sine_macro_core::args::WaveArgs;
+/// use sine_macro_core::codegen::SampleType;
+///
+/// let args: WaveArgs = "frequency: 1_000, rate: 48_000, type: i8, len: 10".parse()?;
+/// assert_eq!(args.ty, SampleType::I8);
+/// assert_eq!(args.spec.generate_int(8)?.len(), 10);
+///
+/// let error = "frequency: 440, len: 10, repeats: 2".parse::<WaveArgs>().unwrap_err();
+/// assert_eq!(error.to_string(), "cannot define both `len` and `repeats`");
+/// # Ok::<(), sine_macro_core::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaveArgs {
+    /// The wave.
+    pub spec: WaveSpec,
+    /// Type of the values.
+    pub ty: SampleType,
+}
+
+/// Parses an integer literal with optional underscores as the value of `name`.
+///
+/// The errors of invalid, negative and zero values are the same as with `sine_wave!`.
+fn parse_int<T: FromStr<Err = ParseIntError>>(name: &str, value: &str) -> Result<T, Error> {
+    if !value
+        .trim_start_matches('-')
+        .starts_with(|c: char| c.is_ascii_digit())
+    {
+        return Err(Error::Argument("expected an integer literal".into()));
+    }
+    let digits: String = value.chars().filter(|c| *c != '_').collect();
+    if digits.parse::<i128>().is_ok_and(|value| value < 0) {
+        return Err(Error::Negative(name.into()));
+    }
+    digits
+        .strip_prefix('-')
+        .unwrap_or(&digits)
+        .parse()
+        .map_err(|error: ParseIntError| Error::Argument(error.to_string()))
+}
+
+/// Returns the value of `values` at the position of `value` in `names`.
+fn parse_choice<T: Copy>(
+    name: &'static str,
+    value: &str,
+    names: &'static [&'static str],
+    values: &[T],
+) -> Result<T, Error> {
+    match names.iter().position(|choice| *choice == value) {
+        Some(i) => Ok(values[i]),
+        None => Err(Error::InvalidChoice(name, names)),
+    }
+}
+
+fn set_once<T>(value: &mut Option<T>, name: &str, attr_value: T) -> Result<(), Error> {
+    if value.is_none() {
+        *value = Some(attr_value);
+        Ok(())
+    } else {
+        Err(Error::DefinedTwice(name.into()))
+    }
+}
+
+impl FromStr for WaveArgs {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Error> {
+        let mut frequency = None;
+        let mut rate = None;
+        let mut len: Option<usize> = None;
+        let mut repeats: Option<usize> = None;
+        let mut skip = None;
+        let mut ty = None;
+        let mut modulation = None;
+        let mut modulator = None;
+        let mut depth = None;
+        let mut deviation = None;
+        let mut rounding = None;
+        let mut dither = None;
+        let mut full_scale = None;
+        let attrs = input.trim().trim_end_matches(',');
+        for attr in attrs.split(',').filter(|_| !attrs.is_empty()) {
+            let Some((name, value)) = attr.split_once(':') else {
+                return Err(Error::Argument(format!(
+                    "expected `:` after `{}`",
+                    attr.trim()
+                )));
+            };
+            let (name, value) = (name.trim(), value.trim());
+            match name {
+                "frequency" if frequency.is_some() => return Err(Error::DefinedTwice(name.into())),
+                "rate" if rate.is_some() => return Err(Error::DefinedTwice(name.into())),
+                "frequency" => {
+                    let value: NonZeroU32 = parse_int(name, value)?;
+                    if let Some(rate) = rate {
+                        if rate < value.get() {
+                            return Err(Error::AboveRate("frequency", rate));
+                        }
+                    }
+                    frequency = Some(value.get())
+                }
+                "rate" => {
+                    let value: NonZeroU32 = parse_int(name, value)?;
+                    if let Some(frequency) = frequency {
+                        if frequency > value.get() {
+                            return Err(Error::BelowFrequency(frequency));
+                        }
+                    }
+                    rate = Some(value.get())
+                }
+                "len" | "repeats" if len.is_some() || repeats.is_some() => {
+                    let other = if len.is_some() { "len" } else { "repeats" };
+                    if name == other {
+                        return Err(Error::DefinedTwice(name.into()));
+                    }
+                    return Err(Error::LenAndRepeats);
+                }
+                "len" => len = Some(parse_int::<NonZeroUsize>(name, value)?.get()),
+                "repeats" => {
+                    let value = parse_int(name, value)?;
+                    if value == 0 {
+                        return Err(Error::NotPositive("repeats"));
+                    }
+                    repeats = Some(value)
+                }
+                "skip" => set_once(&mut skip, name, parse_int::<u32>(name, value)? as usize)?,
+                "type" if MACRO_TYPES.contains(&value) || is_fixed(value) => {
+                    return Err(Error::MacroOnly(format!("type: {}", value)));
+                }
+                "type" => {
+                    let Ok(value) = parse_choice(
+                        "type",
+                        value,
+                        SAMPLE_TYPES,
+                        &[
+                            SampleType::I8,
+                            SampleType::I16,
+                            SampleType::I32,
+                            SampleType::I64,
+                            SampleType::F32,
+                            SampleType::F64,
+                        ],
+                    ) else {
+                        return Err(Error::InvalidType {
+                            name: "type",
+                            storage: true,
+                        });
+                    };
+                    set_once(&mut ty, name, value)?
+                }
+                "modulation" => {
+                    let value = parse_choice("modulation", value, MODULATIONS, &[true, false])?;
+                    set_once(&mut modulation, name, value)?
+                }
+                "modulator" => {
+                    let value: NonZeroU32 = parse_int(name, value)?;
+                    set_once(&mut modulator, name, value.get())?
+                }
+                "depth" => {
+                    let value = parse_int(name, value)?;
+                    if value == 0 || value > 100 {
+                        return Err(Error::InvalidDepth);
+                    }
+                    set_once(&mut depth, name, value)?
+                }
+                "deviation" => {
+                    let value: NonZeroU32 = parse_int(name, value)?;
+                    set_once(&mut deviation, name, value.get())?
+                }
+                "rounding" => {
+                    let value = parse_choice(
+                        "rounding",
+                        value,
+                        ROUNDINGS,
+                        &[
+                            Rounding::Truncate,
+                            Rounding::Nearest,
+                            Rounding::Floor,
+                            Rounding::Ceil,
+                        ],
+                    )?;
+                    set_once(&mut rounding, name, value)?
+                }
+                "dither" => set_once(&mut dither, name, parse_int(name, value)?)?,
+                "full_scale" => {
+                    let value = parse_choice(
+                        "full_scale",
+                        value,
+                        FULL_SCALES,
+                        &[FullScale::Symmetric, FullScale::Asymmetric],
+                    )?;
+                    set_once(&mut full_scale, name, value)?
+                }
+                _ if MACRO_ONLY.contains(&name) => return Err(Error::MacroOnly(name.into())),
+                _ => return Err(Error::InvalidIdentifier),
+            }
+        }
+        let Some(frequency) = frequency else {
+            return Err(Error::Undefined("frequency"));
+        };
+        let ty = ty.unwrap_or_default();
+        let mut spec = WaveSpec::new(frequency);
+        if let Some(rate) = rate {
+            spec = spec.with_rate(rate);
+        }
+        if let Some(len) = len {
+            spec = spec.with_len(len);
+        }
+        if let Some(repeats) = repeats {
+            spec = spec.with_repeats(repeats);
+        }
+        if let Some(skip) = skip {
+            spec = spec.with_skip(skip);
+        }
+        match modulation {
+            Some(am) => {
+                let Some(modulator) = modulator else {
+                    return Err(Error::MissingModulator);
+                };
+                if am {
+                    if deviation.is_some() {
+                        return Err(Error::WrongModulation("deviation", "fm"));
+                    }
+                    spec = spec.with_modulation(Modulation::Am {
+                        modulator,
+                        depth: depth.unwrap_or(100),
+                    });
+                } else {
+                    if depth.is_some() {
+                        return Err(Error::WrongModulation("depth", "am"));
+                    }
+                    let Some(deviation) = deviation else {
+                        return Err(Error::MissingDeviation);
+                    };
+                    spec = spec.with_modulation(Modulation::Fm {
+                        modulator,
+                        deviation,
+                    });
+                }
+            }
+            None => {
+                for (name, defined) in [
+                    ("modulator", modulator.is_some()),
+                    ("depth", depth.is_some()),
+                    ("deviation", deviation.is_some()),
+                ] {
+                    if defined {
+                        return Err(Error::RequiresModulation(name.into()));
+                    }
+                }
+            }
+        }
+        if ty.is_float() {
+            for (name, defined) in [
+                ("rounding", rounding.is_some()),
+                ("dither", dither.is_some()),
+                ("full_scale", full_scale.is_some()),
+            ] {
+                if defined {
+                    return Err(Error::FloatingPoint(name.into()));
+                }
+            }
+        }
+        if let Some(rounding) = rounding {
+            spec = spec.with_rounding(rounding);
+        }
+        if let Some(dither) = dither {
+            spec = spec.with_dither(dither);
+        }
+        if let Some(full_scale) = full_scale {
+            spec = spec.with_full_scale(full_scale);
+        }
+        spec.check()?;
+        Ok(WaveArgs { spec, ty })
+    }
+}
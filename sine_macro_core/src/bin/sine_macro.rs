@@ -0,0 +1,221 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! Command-line tool for generating and inspecting sine wave tables.
+//!
+//! Takes the same arguments as `sine_wave!` and prints the table as Rust or C code, a C header,
+//! CSV or an ASCII plot, or information about it, or writes it as a WAV file.
+
+use sine_macro_core::args::WaveArgs;
+use sine_macro_core::codegen::{Generator, Item};
+use sine_macro_core::{Error, Wav};
+use std::fs;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: sine_macro [OPTIONS] ARGUMENTS...
+
+Generates a sine wave table from the same ARGUMENTS as sine_wave!, e.g.
+    sine_macro frequency: 440, rate: 48_000, type: i8
+
+Options:
+  -f, --format FORMAT  Output format, one of rust, c, header, csv, plot, info and wav
+                       (default: rust)
+  -n, --name NAME      Name of the generated item (default: WAVE)
+  -c, --channels N     Number of channels in WAV files (default: 1)
+  -o, --output FILE    Write the output to FILE instead of standard output
+  -h, --help           Print this help";
+
+/// Number of rows in plots, which is odd so that zero has its own row.
+const PLOT_ROWS: usize = 17;
+
+/// Maximum number of columns in plots.
+const PLOT_COLUMNS: usize = 72;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Rust,
+    C,
+    Header,
+    Csv,
+    Plot,
+    Info,
+    Wav,
+}
+
+struct Options {
+    format: Format,
+    name: String,
+    channels: u16,
+    output: Option<String>,
+    args: String,
+}
+
+fn parse_options(mut argv: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut format = Format::Rust;
+    let mut name = String::from("WAVE");
+    let mut channels = 1;
+    let mut output = None;
+    let mut args = Vec::new();
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--format" => {
+                format = match argv.next().as_deref() {
+                    Some("rust") => Format::Rust,
+                    Some("c") => Format::C,
+                    Some("header") => Format::Header,
+                    Some("csv") => Format::Csv,
+                    Some("plot") => Format::Plot,
+                    Some("info") => Format::Info,
+                    Some("wav") => Format::Wav,
+                    _ => {
+                        return Err("`--format` must be one of `rust`, `c`, `header`, `csv`, \
+                             `plot`, `info` and `wav`"
+                            .into());
+                    }
+                }
+            }
+            "-n" | "--name" => {
+                name = argv.next().ok_or("`--name` requires a value")?;
+            }
+            "-c" | "--channels" => {
+                channels = argv
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or("`--channels` requires a number")?;
+            }
+            "-o" | "--output" => {
+                output = Some(argv.next().ok_or("`--output` requires a file")?);
+            }
+            _ => args.push(arg),
+        }
+    }
+    Ok(Some(Options {
+        format,
+        name,
+        channels,
+        output,
+        args: args.join(" "),
+    }))
+}
+
+/// Returns the values in range `-1.0..=1.0`.
+fn normalized(args: &WaveArgs) -> Result<Vec<f64>, Error> {
+    if args.ty.is_float() {
+        return args.spec.generate();
+    }
+    let max = (i64::MAX >> (64 - args.ty.bits())) as f64;
+    let values = args.spec.generate_int(args.ty.bits())?;
+    Ok(values.into_iter().map(|value| value as f64 / max).collect())
+}
+
+fn plot(values: &[f64]) -> String {
+    let columns = values.len().min(PLOT_COLUMNS);
+    let half = (PLOT_ROWS / 2) as f64;
+    let rows: Vec<_> = (0..columns)
+        .map(|column| {
+            let value = values[column * values.len() / columns].clamp(-1_f64, 1_f64);
+            ((1_f64 - value) * half).round() as usize
+        })
+        .collect();
+    let mut output = String::new();
+    for row in 0..PLOT_ROWS {
+        let label = match row {
+            0 => " 1",
+            _ if row == PLOT_ROWS / 2 => " 0",
+            _ if row == PLOT_ROWS - 1 => "-1",
+            _ => "  ",
+        };
+        let line: String = rows
+            .iter()
+            .map(|point| match *point == row {
+                true => '*',
+                false if row == PLOT_ROWS / 2 => '-',
+                false => ' ',
+            })
+            .collect();
+        output.push_str(&format!("{} |{}\n", label, line.trim_end()));
+    }
+    output
+}
+
+fn info(args: &WaveArgs) -> Result<String, Error> {
+    let count = args.spec.count()?;
+    let size = count * args.ty.bits() as usize / 8;
+    let mut output = format!("frequency: {} Hz\n", args.spec.frequency());
+    output.push_str(&format!(
+        "effective frequency: {:.3} Hz\n",
+        args.spec.effective_frequency()?
+    ));
+    output.push_str(&format!("rate: {} Hz\n", args.spec.rate()));
+    output.push_str(&format!("type: {}\n", args.ty.name()));
+    output.push_str(&format!("samples: {}\n", count));
+    output.push_str(&format!("size: {} bytes\n", size));
+    Ok(output)
+}
+
+fn run(options: &Options) -> Result<Vec<u8>, Error> {
+    let args: WaveArgs = options.args.parse()?;
+    let item = Item::new(options.name.clone(), args.spec.clone()).with_type(args.ty);
+    let output = match options.format {
+        Format::Rust => item.to_rust(),
+        Format::C => item.to_c(),
+        Format::Header => {
+            let guard = format!("{}_H", options.name.to_ascii_uppercase());
+            Generator::new().item(item).generate_c_header(&guard)
+        }
+        Format::Csv => {
+            let mut output = String::from("index,value\n");
+            for (i, value) in args.ty.literals(&args.spec)?.into_iter().enumerate() {
+                output.push_str(&format!("{},{}\n", i, value));
+            }
+            Ok(output)
+        }
+        Format::Plot => Ok(plot(&normalized(&args)?)),
+        Format::Info => info(&args),
+        Format::Wav => {
+            return Wav::new(args.spec, args.ty)
+                .with_channels(options.channels)
+                .encode();
+        }
+    };
+    output.map(String::into_bytes)
+}
+
+fn write_output(options: &Options, output: &[u8]) -> io::Result<()> {
+    match &options.output {
+        Some(path) => fs::write(path, output),
+        None => io::stdout().write_all(output),
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(&options) {
+        Ok(output) => match write_output(&options, &output) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("error: {}", error);
+                ExitCode::FAILURE
+            }
+        },
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,473 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! Rust and C source code generation for build scripts.
+
+use crate::{Error, Modulation, WaveSpec};
+use std::fmt::{Display, Write as _};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// Maximum length of lines with values in generated code.
+const LINE_WIDTH: usize = 100;
+
+/// Type of the values in a table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SampleType {
+    /// `i8`
+    I8,
+    /// `i16`
+    #[default]
+    I16,
+    /// `i32`
+    I32,
+    /// `i64`
+    I64,
+    /// `f32`
+    F32,
+    /// `f64`
+    F64,
+}
+
+impl SampleType {
+    /// Returns the name of the type in Rust.
+    pub fn name(self) -> &'static str {
+        match self {
+            SampleType::I8 => "i8",
+            SampleType::I16 => "i16",
+            SampleType::I32 => "i32",
+            SampleType::I64 => "i64",
+            SampleType::F32 => "f32",
+            SampleType::F64 => "f64",
+        }
+    }
+
+    /// Returns the number of bits in the type.
+    pub fn bits(self) -> u32 {
+        match self {
+            SampleType::I8 => 8,
+            SampleType::I16 => 16,
+            SampleType::I32 | SampleType::F32 => 32,
+            SampleType::I64 | SampleType::F64 => 64,
+        }
+    }
+
+    /// Returns the name of the type in C.
+    pub fn c_name(self) -> &'static str {
+        match self {
+            SampleType::I8 => "int8_t",
+            SampleType::I16 => "int16_t",
+            SampleType::I32 => "int32_t",
+            SampleType::I64 => "int64_t",
+            SampleType::F32 => "float",
+            SampleType::F64 => "double",
+        }
+    }
+
+    /// Returns true if the type is a floating point type.
+    pub fn is_float(self) -> bool {
+        matches!(self, SampleType::F32 | SampleType::F64)
+    }
+
+    /// Returns the values of `spec` formatted as Rust literals of the type.
+    pub fn literals(self, spec: &WaveSpec) -> Result<Vec<String>, Error> {
+        Ok(match self {
+            SampleType::F32 => spec
+                .generate()?
+                .into_iter()
+                .map(|value| format!("{:?}", value as f32))
+                .collect(),
+            SampleType::F64 => spec
+                .generate()?
+                .into_iter()
+                .map(|value| format!("{:?}", value))
+                .collect(),
+            _ => spec
+                .generate_int(self.bits())?
+                .into_iter()
+                .map(|value| value.to_string())
+                .collect(),
+        })
+    }
+}
+
+/// Kind of a generated item, like the `const` and `static` forms of `sine_wave!`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ItemKind {
+    /// `const NAME: [T; N] = ...;`
+    #[default]
+    Const,
+    /// `static NAME: [T; N] = ...;`
+    Static,
+    /// `static mut NAME: [T; N] = ...;`
+    StaticMut,
+}
+
+/// Visibility of a generated item.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Visibility {
+    /// Private to the module that includes the code.
+    #[default]
+    Private,
+    /// `pub`
+    Public,
+    /// `pub(crate)`
+    Crate,
+}
+
+impl Visibility {
+    fn prefix(self) -> &'static str {
+        match self {
+            Visibility::Private => "",
+            Visibility::Public => "pub ",
+            Visibility::Crate => "pub(crate) ",
+        }
+    }
+}
+
+/// A table that is generated as a `const` or `static` item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+    name: String,
+    spec: WaveSpec,
+    kind: ItemKind,
+    visibility: Visibility,
+    ty: SampleType,
+}
+
+impl Item {
+    /// Returns a private `const` item of `i16` values called `name`.
+    pub fn new(name: impl Into<String>, spec: WaveSpec) -> Self {
+        Item {
+            name: name.into(),
+            spec,
+            kind: ItemKind::Const,
+            visibility: Visibility::Private,
+            ty: SampleType::I16,
+        }
+    }
+
+    /// Sets the kind of the item.
+    pub fn with_kind(mut self, kind: ItemKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the visibility of the item.
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Sets the type of the values.
+    pub fn with_type(mut self, ty: SampleType) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    /// Returns the name of the item.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the spec of the wave.
+    pub fn spec(&self) -> &WaveSpec {
+        &self.spec
+    }
+
+    /// Returns the type of the values.
+    pub fn ty(&self) -> SampleType {
+        self.ty
+    }
+
+    /// Checks that the name is a valid identifier.
+    pub(crate) fn check_name(&self) -> Result<(), Error> {
+        let mut chars = self.name.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && self.name != "_";
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidName(self.name.clone()))
+        }
+    }
+
+    /// Returns the item as Rust code.
+    pub fn to_rust(&self) -> Result<String, Error> {
+        let mut output = String::new();
+        self.check_name()?;
+        let values = self.ty.literals(&self.spec)?;
+        let keyword = match self.kind {
+            ItemKind::Const => "const",
+            ItemKind::Static => "static",
+            ItemKind::StaticMut => "static mut",
+        };
+        writeln!(output, "/// {}", self.description()).unwrap();
+        if self.ty.is_float() {
+            writeln!(output, "#[allow(clippy::approx_constant)]").unwrap();
+        }
+        writeln!(
+            output,
+            "{}{} {}: [{}; {}] = [",
+            self.visibility.prefix(),
+            keyword,
+            self.name,
+            self.ty.name(),
+            values.len()
+        )
+        .unwrap();
+        write_values(&mut output, &values, "    ", ",");
+        writeln!(output, "];").unwrap();
+        Ok(output)
+    }
+
+    /// Returns the item as a C array definition with `NAME_FREQUENCY`, `NAME_RATE` and
+    /// `NAME_LEN` macros for the frequency, the sampling rate and the length of the array.
+    ///
+    /// `static mut` items are not `const` in C and the visibility is ignored, as `static` arrays
+    /// are always private to the file in C. The values are the same as with [`to_rust`].
+    ///
+    /// [`to_rust`]: Item::to_rust
+    pub fn to_c(&self) -> Result<String, Error> {
+        let mut output = String::new();
+        self.check_name()?;
+        let values: Vec<_> = self
+            .ty
+            .literals(&self.spec)?
+            .into_iter()
+            .map(|value| match self.ty {
+                SampleType::F32 => format!("{}f", value),
+                SampleType::F64 => value,
+                ty => {
+                    // The minimum is written as a subtraction, as C has no negative literals and
+                    // its absolute value does not fit in the type
+                    let min = -1_i64 << (ty.bits() - 1);
+                    match value == min.to_string() {
+                        true => format!("({} - 1)", c_int(ty, min + 1)),
+                        false => c_int(ty, value),
+                    }
+                }
+            })
+            .collect();
+        let qualifier = match self.kind {
+            ItemKind::Const | ItemKind::Static => "static const",
+            ItemKind::StaticMut => "static",
+        };
+        writeln!(output, "/* {} */", self.description()).unwrap();
+        for (suffix, value) in [
+            ("FREQUENCY", self.spec.frequency() as usize),
+            ("RATE", self.spec.rate() as usize),
+            ("LEN", values.len()),
+        ] {
+            writeln!(output, "#define {}_{} {}", self.name, suffix, value).unwrap();
+        }
+        writeln!(
+            output,
+            "{} {} {}[{}_LEN] = {{",
+            qualifier,
+            self.ty.c_name(),
+            self.name,
+            self.name
+        )
+        .unwrap();
+        write_values(&mut output, &values, "    ", ",");
+        writeln!(output, "}};").unwrap();
+        Ok(output)
+    }
+
+    /// Returns a description of the wave for comments.
+    fn description(&self) -> String {
+        let wave = match self.spec.modulation() {
+            None => format!("{} Hz sine wave", self.spec.frequency()),
+            Some(Modulation::Am { modulator, depth }) => format!(
+                "{} Hz carrier amplitude modulated by {} Hz with {}% depth",
+                self.spec.frequency(),
+                modulator,
+                depth
+            ),
+            Some(Modulation::Fm {
+                modulator,
+                deviation,
+            }) => format!(
+                "{} Hz carrier frequency modulated by {} Hz with {} Hz deviation",
+                self.spec.frequency(),
+                modulator,
+                deviation
+            ),
+        };
+        format!("{} at {} Hz sampling rate.", wave, self.spec.rate())
+    }
+}
+
+/// Returns `value` as a C integer literal of `ty`.
+fn c_int(ty: SampleType, value: impl Display) -> String {
+    match ty {
+        SampleType::I64 => format!("INT64_C({})", value),
+        _ => value.to_string(),
+    }
+}
+
+/// Writes `values` separated with `separator` on lines that start with `indent`.
+pub(crate) fn write_values(output: &mut String, values: &[String], indent: &str, separator: &str) {
+    let mut line = String::new();
+    for value in values {
+        if !line.is_empty()
+            && indent.len() + line.len() + value.len() + separator.len() > LINE_WIDTH
+        {
+            writeln!(output, "{}{}", indent, line.trim_end()).unwrap();
+            line.clear();
+        }
+        write!(line, "{}{} ", value, separator).unwrap();
+    }
+    if !line.is_empty() {
+        writeln!(output, "{}{}", indent, line.trim_end()).unwrap();
+    }
+}
+
+/// Builder for a Rust source file with tables, e.g. for `include!` from `OUT_DIR`, or a C header
+/// with the same tables.
+///
+/// # Example
+/// In `build.rs`:
+/// ```rust,no_run
+/// use sine_macro_core::WaveSpec;
+/// use sine_macro_core::codegen::{Generator, Item, ItemKind, SampleType, Visibility};
+///
+/// let beep = WaveSpec::new(440).with_rate(48_000);
+/// let long_beep = WaveSpec::new(1_000).with_rate(48_000).with_len(48_000);
+/// let generator = Generator::new()
+///     .item(Item::new("BEEP", beep).with_visibility(Visibility::Public))
+///     .item(
+///         Item::new("LONG_BEEP", long_beep)
+///             .with_kind(ItemKind::Static)
+///             .with_type(SampleType::I8),
+///     );
+/// generator.write_to_out_dir("tables.rs").unwrap();
+/// // The same tables for the C side of the firmware
+/// generator.write_c_header("c_src/tables.h").unwrap();
+/// ```
+///
+/// And in the crate:
+/// ```rust,ignore
+/// include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Generator {
+    items: Vec<Item>,
+}
+
+impl Generator {
+    /// Returns a generator without items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item to the generated code.
+    pub fn item(mut self, item: Item) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Returns the items of the generator.
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Returns the generated Rust code.
+    pub fn generate(&self) -> Result<String, Error> {
+        let mut output = String::from("// Generated by sine_macro_core. Do not edit.\n");
+        for item in &self.items {
+            output.push('\n');
+            output.push_str(&item.to_rust()?);
+        }
+        Ok(output)
+    }
+
+    /// Returns a C header with the items as arrays, which has `guard` as its include guard.
+    ///
+    /// See [`Item::to_c`] for what is generated for each item.
+    pub fn generate_c_header(&self, guard: &str) -> Result<String, Error> {
+        let mut output = String::from("/* Generated by sine_macro_core. Do not edit. */\n\n");
+        writeln!(output, "#ifndef {}\n#define {}\n", guard, guard).unwrap();
+        writeln!(output, "#include <stdint.h>").unwrap();
+        for item in &self.items {
+            output.push('\n');
+            output.push_str(&item.to_c()?);
+        }
+        writeln!(output, "\n#endif /* {} */", guard).unwrap();
+        Ok(output)
+    }
+
+    /// Writes the generated Rust code to `path`.
+    ///
+    /// The file is left untouched if its contents would not change, so that it does not trigger
+    /// rebuilds. Errors of the items are returned as [`io::ErrorKind::InvalidInput`].
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let code = self
+            .generate()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        write_if_changed(path.as_ref(), &code)
+    }
+
+    /// Writes a C header to `path`.
+    ///
+    /// The include guard is the file name in upper case, e.g. `TABLES_H` for `tables.h`. Like
+    /// with [`write`](Generator::write), the file is left untouched if it would not change.
+    pub fn write_c_header(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let guard = header_guard(path);
+        let code = self
+            .generate_c_header(&guard)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        write_if_changed(path, &code)
+    }
+
+    /// Writes the generated Rust code to `file_name` in `OUT_DIR` and returns the path.
+    ///
+    /// This is meant to be called from build scripts, where Cargo sets `OUT_DIR`.
+    pub fn write_to_out_dir(&self, file_name: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let path = out_dir()?.join(file_name);
+        self.write(&path)?;
+        Ok(path)
+    }
+}
+
+/// Returns the include guard for a header at `path`.
+fn header_guard(path: &Path) -> String {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let guard: String = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_uppercase(),
+            false => '_',
+        })
+        .collect();
+    match guard.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("_{}", guard),
+        false => guard,
+    }
+}
+
+/// Returns the path in `OUT_DIR`.
+pub(crate) fn out_dir() -> io::Result<PathBuf> {
+    env::var_os("OUT_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "`OUT_DIR` is not set"))
+}
+
+/// Writes `contents` to `path` unless the file already has the same contents.
+pub(crate) fn write_if_changed(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let contents = contents.as_ref();
+    if fs::read(path).is_ok_and(|old| old == contents) {
+        return Ok(());
+    }
+    fs::write(path, contents)
+}
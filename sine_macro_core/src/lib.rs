@@ -0,0 +1,217 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! The sine wave generation of [sine_macro](https://docs.rs/sine_macro) as a library.
+//!
+//! `sine_wave!` is a thin wrapper around this crate, so the values are the same as the macro
+//! generates, but they can also be computed at run time, e.g. in build scripts, tests or host
+//! tools. A wave is described with [`WaveSpec`], whose methods correspond to the arguments of
+//! `sine_wave!`. Build scripts can write the waves as Rust or C code with [`codegen`] or as WAV
+//! files for listening with [`Wav`], and the macro arguments can be parsed from text with
+//! [`args`].
+//!
+//! # Example
+//! ```rust
+//! use sine_macro_core::{Rounding, WaveSpec};
+//!
+//! // Same as `sine_wave!(frequency: 441, rate: 44_100, repeats: 2)`
+//! let spec = WaveSpec::new(441).with_rate(44_100).with_repeats(2);
+//! let wave = spec.generate_int(16)?;
+//! assert_eq!(wave.len(), 200);
+//! assert_eq!(wave[25], i16::MAX as i64);
+//!
+//! // Same as `sine_wave!(frequency: 1_000, rate: 48_000, type: i8, rounding: nearest)`
+//! let spec = WaveSpec::new(1_000)
+//!     .with_rate(48_000)
+//!     .with_rounding(Rounding::Nearest);
+//! assert_eq!(spec.generate_int(8)?.len(), 48);
+//!
+//! // Same as `sine_wave!(frequency: 1_000, rate: 48_000, type: f64)`
+//! assert_eq!(spec.generate()?[12], 1.0);
+//! # Ok::<(), sine_macro_core::Error>(())
+//! ```
+//!
+//! # Features
+//!
+//! - `cli`: Builds the `sine_macro` command-line tool, which takes the same arguments as
+//!   `sine_wave!` and prints the table as Rust or C code, a C header, CSV or an ASCII plot, or its
+//!   effective frequency and size with `--format info`. `--format wav` writes a WAV file instead.
+
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+pub mod args;
+pub mod codegen;
+mod quantize;
+mod spec;
+mod wav;
+mod wave;
+pub use crate::quantize::{FullScale, Rounding, SplitMix64, quantize};
+pub use crate::spec::{Modulation, WaveSpec};
+pub use crate::wav::{Wav, WavFormat};
+pub use crate::wave::{
+    arrange, cosine_wave, gcd, lagging_sine_wave, modulated_wave, period, sine_wave,
+};
+
+/// Sampling rate that is used when it is not specified.
+pub const DEFAULT_RATE: u32 = 44_100;
+
+/// Error for a [`WaveSpec`] that does not describe a valid wave.
+///
+/// The messages are the same as the errors of `sine_wave!` for the same arguments where the
+/// macro has an equivalent error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The named argument is zero.
+    NotPositive(&'static str),
+    /// The named argument is negative.
+    Negative(String),
+    /// The named frequency is higher than the sampling rate.
+    AboveRate(&'static str, u32),
+    /// The sampling rate is lower than the frequency.
+    BelowFrequency(u32),
+    /// The depth of amplitude modulation is not a percentage between 1 and 100.
+    InvalidDepth,
+    /// The deviation of frequency modulation is not less than the frequency.
+    InvalidDeviation(u32),
+    /// The number of bits is not supported.
+    InvalidBits(u32),
+    /// The name of a generated item is not a valid identifier.
+    InvalidName(String),
+    /// Arguments parsed from text are invalid, with the error message.
+    Argument(String),
+    /// The name of an argument is not one of [`args::ARGUMENTS`].
+    InvalidIdentifier,
+    /// The value of the named argument is not one of [`args::TYPES`].
+    InvalidType {
+        /// Name of the argument.
+        name: &'static str,
+        /// Whether any other type is allowed with `storage`.
+        storage: bool,
+    },
+    /// The value of the named argument is not one of the listed choices.
+    InvalidChoice(&'static str, &'static [&'static str]),
+    /// The named argument is given more than once.
+    DefinedTwice(String),
+    /// Both `len` and `repeats` are given.
+    LenAndRepeats,
+    /// The named required argument is not given.
+    Undefined(&'static str),
+    /// The named argument is supported only by `sine_wave!`.
+    MacroOnly(String),
+    /// Modulation is used without `modulator`.
+    MissingModulator,
+    /// Frequency modulation is used without `deviation`.
+    MissingDeviation,
+    /// The named argument is used with the other kind of modulation than the one given.
+    WrongModulation(&'static str, &'static str),
+    /// The named argument is used without `modulation`.
+    RequiresModulation(String),
+    /// The named argument is used with a floating point type.
+    FloatingPoint(String),
+    /// The named sample type cannot be written to WAV files.
+    UnsupportedType(&'static str),
+    /// A WAV file could not be parsed, with the reason.
+    InvalidWav(&'static str),
+    /// The wave would contain only zeros.
+    Silent {
+        /// Sampling rate of the wave.
+        rate: u32,
+        /// Frequency of the wave.
+        frequency: u32,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NotPositive(name) => write!(f, "`{}` must be positive", name),
+            Error::Negative(name) => write!(f, "`{}` must not be negative", name),
+            Error::AboveRate(name, rate) => write!(
+                f,
+                "`{}` should be less than `rate`, which is {} Hz",
+                name, rate
+            ),
+            Error::BelowFrequency(frequency) => write!(
+                f,
+                "`rate` should be more than `frequency`, which is {} Hz",
+                frequency
+            ),
+            Error::InvalidDepth => write!(f, "`depth` must be a percentage between 1 and 100"),
+            Error::InvalidDeviation(frequency) => write!(
+                f,
+                "`deviation` should be less than `frequency`, which is {} Hz",
+                frequency
+            ),
+            Error::InvalidBits(bits) => {
+                write!(f, "integers must have between 2 and 64 bits, not {}", bits)
+            }
+            Error::InvalidName(name) => write!(f, "`{}` is not a valid identifier", name),
+            Error::Argument(message) => f.write_str(message),
+            Error::InvalidIdentifier => {
+                f.write_str("invalid identifier, must be one of ")?;
+                write_list(f, args::ARGUMENTS)
+            }
+            Error::InvalidType { name, storage } => {
+                write!(
+                    f,
+                    "invalid value for `{}`, must be one of {}",
+                    name,
+                    args::TYPES
+                )?;
+                match storage {
+                    true => f.write_str(" or any other type with `storage`"),
+                    false => Ok(()),
+                }
+            }
+            Error::InvalidChoice(name, choices) => {
+                write!(f, "invalid value for `{}`, must be one of ", name)?;
+                write_list(f, choices)
+            }
+            Error::DefinedTwice(name) => write!(f, "`{}` defined twice", name),
+            Error::LenAndRepeats => f.write_str("cannot define both `len` and `repeats`"),
+            Error::Undefined(name) => write!(f, "`{}` must be defined", name),
+            Error::MacroOnly(name) => write!(f, "`{}` is supported only by `sine_wave!`", name),
+            Error::MissingModulator => f.write_str("`modulator` must be defined for modulation"),
+            Error::MissingDeviation => {
+                f.write_str("`deviation` must be defined for `modulation: fm`")
+            }
+            Error::WrongModulation(name, modulation) => write!(
+                f,
+                "`{}` can be used only with `modulation: {}`",
+                name, modulation
+            ),
+            Error::RequiresModulation(name) => write!(f, "`{}` requires `modulation`", name),
+            Error::FloatingPoint(name) => {
+                write!(f, "`{}` cannot be used with floating point types", name)
+            }
+            Error::UnsupportedType(ty) => write!(f, "`{}` cannot be written to WAV files", ty),
+            Error::InvalidWav(reason) => write!(f, "invalid WAV file: {}", reason),
+            Error::Silent { rate, frequency } => write!(
+                f,
+                "could not generate sine wave for `rate` of {} Hz and `frequency` of {} Hz",
+                rate, frequency
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Writes `names` quoted as a list, e.g. "`a`, `b` and `c`".
+fn write_list(f: &mut fmt::Formatter, names: &[&str]) -> fmt::Result {
+    for (i, name) in names.iter().enumerate() {
+        match i {
+            0 => (),
+            _ if i == names.len() - 1 => f.write_str(" and ")?,
+            _ => f.write_str(", ")?,
+        }
+        write!(f, "`{}`", name)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+/// How values are rounded to integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds towards zero.
+    #[default]
+    Truncate,
+    /// Rounds to the nearest integer and half way away from zero.
+    Nearest,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+}
+
+impl Rounding {
+    /// Rounds `value` to an integer.
+    pub fn round(self, value: f64) -> f64 {
+        match self {
+            Rounding::Truncate => value.trunc(),
+            Rounding::Nearest => value.round(),
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+        }
+    }
+}
+
+/// Whether negative values reach `MIN` of the type or stop at `-MAX`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FullScale {
+    /// Values are in range `-MAX..=MAX`.
+    #[default]
+    Symmetric,
+    /// Values are in range `MIN..=MAX`.
+    Asymmetric,
+}
+
+/// SplitMix64 pseudorandom number generator.
+///
+/// This is not cryptographically secure but it is simple, fast and the output only depends on
+/// the seed, which is all that is needed for reproducible tables.
+#[derive(Clone, Debug)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Returns a generator that is seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    /// Returns the next pseudorandom value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.0;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    /// Returns a uniformly distributed value in range `[-1, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 52) as f64 - 1_f64
+    }
+}
+
+/// Scales the wave to integers in range `min..=max` with optional triangular dither.
+///
+/// Positive values are scaled with `max` and negative values with `-min`, so that `1.0` maps to
+/// `max` and `-1.0` maps to `min`. The dither is seeded with `dither`.
+///
+/// The values are clamped after converting them to integers, as `f64` cannot represent the
+/// extremes of 64-bit and wider types exactly.
+pub fn quantize(
+    wave: &[f64],
+    min: i128,
+    max: i128,
+    rounding: Rounding,
+    dither: Option<u64>,
+) -> Vec<i128> {
+    let mut rng = dither.map(SplitMix64::new);
+    wave.iter()
+        .map(|value| {
+            let mut value = if *value < 0_f64 {
+                value * -(min as f64)
+            } else {
+                value * max as f64
+            };
+            if let Some(rng) = &mut rng {
+                value += (rng.next_f64() + rng.next_f64()) / 2_f64;
+            }
+            (rounding.round(value) as i128).clamp(min, max)
+        })
+        .collect()
+}
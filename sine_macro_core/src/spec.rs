@@ -0,0 +1,262 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::{DEFAULT_RATE, Error, FullScale, Rounding, arrange, modulated_wave, quantize};
+
+/// Modulation of a wave, like `modulation` of `sine_wave!`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Modulation {
+    /// Amplitude modulation with `depth` percent by a sine wave of `modulator` Hz.
+    Am {
+        /// Frequency of the modulator in Hz.
+        modulator: u32,
+        /// Depth of the modulation as a percentage between 1 and 100.
+        depth: u32,
+    },
+    /// Frequency modulation with a peak `deviation` in Hz by a sine wave of `modulator` Hz.
+    Fm {
+        /// Frequency of the modulator in Hz.
+        modulator: u32,
+        /// Peak deviation of the frequency in Hz.
+        deviation: u32,
+    },
+}
+
+impl Modulation {
+    /// Returns the frequency of the modulator.
+    pub fn modulator(self) -> u32 {
+        match self {
+            Modulation::Am { modulator, .. } | Modulation::Fm { modulator, .. } => modulator,
+        }
+    }
+}
+
+/// Description of a sine wave with the same options as `sine_wave!`.
+///
+/// `with_len` and `with_repeats` replace each other, as the macro does not allow both of them
+/// either. The spec is checked when the values are generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaveSpec {
+    frequency: u32,
+    rate: u32,
+    len: Option<usize>,
+    repeats: Option<usize>,
+    skip: usize,
+    modulation: Option<Modulation>,
+    rounding: Rounding,
+    full_scale: FullScale,
+    dither: Option<u64>,
+}
+
+impl WaveSpec {
+    /// Returns a spec for a wave of `frequency` at the default rate of 44,100 Hz.
+    pub fn new(frequency: u32) -> Self {
+        WaveSpec {
+            frequency,
+            rate: DEFAULT_RATE,
+            len: None,
+            repeats: None,
+            skip: 0,
+            modulation: None,
+            rounding: Rounding::Truncate,
+            full_scale: FullScale::Symmetric,
+            dither: None,
+        }
+    }
+
+    /// Sets the sampling rate.
+    pub fn with_rate(mut self, rate: u32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Sets the number of samples, which replaces the number of repeats.
+    pub fn with_len(mut self, len: usize) -> Self {
+        self.len = Some(len);
+        self.repeats = None;
+        self
+    }
+
+    /// Sets the number of repeated periods, which replaces the number of samples.
+    pub fn with_repeats(mut self, repeats: usize) -> Self {
+        self.repeats = Some(repeats);
+        self.len = None;
+        self
+    }
+
+    /// Sets the number of samples that are skipped from the start of the wave.
+    pub fn with_skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    /// Sets the modulation of the wave.
+    pub fn with_modulation(mut self, modulation: Modulation) -> Self {
+        self.modulation = Some(modulation);
+        self
+    }
+
+    /// Sets the rounding of integer values.
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the range of negative integer values.
+    pub fn with_full_scale(mut self, full_scale: FullScale) -> Self {
+        self.full_scale = full_scale;
+        self
+    }
+
+    /// Sets the seed of triangular dither that is added to integer values before rounding.
+    pub fn with_dither(mut self, seed: u64) -> Self {
+        self.dither = Some(seed);
+        self
+    }
+
+    /// Returns the frequency of the wave.
+    pub fn frequency(&self) -> u32 {
+        self.frequency
+    }
+
+    /// Returns the sampling rate of the wave.
+    pub fn rate(&self) -> u32 {
+        self.rate
+    }
+
+    /// Returns the modulation of the wave.
+    pub fn modulation(&self) -> Option<Modulation> {
+        self.modulation
+    }
+
+    /// Returns the frequency of the generated wave, which is higher than [`frequency`] when the
+    /// period is rounded down.
+    ///
+    /// [`frequency`]: WaveSpec::frequency
+    pub fn effective_frequency(&self) -> Result<f64, Error> {
+        self.check()?;
+        match self.modulation {
+            Some(_) => Ok(self.frequency as f64),
+            None => Ok(self.rate as f64 / crate::period(self.frequency, self.rate) as f64),
+        }
+    }
+
+    /// Checks that the spec describes a valid wave.
+    pub fn check(&self) -> Result<(), Error> {
+        for (name, value) in [
+            ("frequency", self.frequency as usize),
+            ("rate", self.rate as usize),
+            ("len", self.len.unwrap_or(1)),
+            ("repeats", self.repeats.unwrap_or(1)),
+        ] {
+            if value == 0 {
+                return Err(Error::NotPositive(name));
+            }
+        }
+        if self.rate < self.frequency {
+            return Err(Error::AboveRate("frequency", self.rate));
+        }
+        match self.modulation {
+            Some(Modulation::Am { modulator, depth }) => {
+                self.check_modulator(modulator)?;
+                if depth == 0 || depth > 100 {
+                    return Err(Error::InvalidDepth);
+                }
+            }
+            Some(Modulation::Fm {
+                modulator,
+                deviation,
+            }) => {
+                self.check_modulator(modulator)?;
+                if deviation == 0 {
+                    return Err(Error::NotPositive("deviation"));
+                }
+                if self.frequency <= deviation {
+                    return Err(Error::InvalidDeviation(self.frequency));
+                }
+            }
+            None => (),
+        }
+        Ok(())
+    }
+
+    fn check_modulator(&self, modulator: u32) -> Result<(), Error> {
+        if modulator == 0 {
+            Err(Error::NotPositive("modulator"))
+        } else if self.rate <= modulator {
+            Err(Error::AboveRate("modulator", self.rate))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns one period of the wave in range `-1.0..=1.0`, or the shortest loop of a modulated
+    /// wave, without `len`, `repeats` and `skip` applied.
+    pub fn wave(&self) -> Result<Vec<f64>, Error> {
+        self.check()?;
+        Ok(match self.modulation {
+            Some(modulation) => modulated_wave(self.frequency, self.rate, modulation),
+            None => crate::sine_wave(self.frequency, self.rate),
+        })
+    }
+
+    /// Repeats `period` and applies `len`, `repeats` and `skip` to it.
+    ///
+    /// This can be used for tables that are derived from [`wave`](WaveSpec::wave).
+    pub fn arrange<T: Clone>(&self, period: &[T]) -> Vec<T> {
+        arrange(period, self.len, self.repeats, self.skip)
+    }
+
+    /// Returns the number of values that are generated.
+    pub fn count(&self) -> Result<usize, Error> {
+        let period = self.wave()?.len();
+        Ok(self.len.unwrap_or(period * self.repeats.unwrap_or(1)))
+    }
+
+    /// Returns the wave in range `-1.0..=1.0` like `sine_wave!` does for `f64`.
+    pub fn generate(&self) -> Result<Vec<f64>, Error> {
+        let wave = self.wave()?;
+        if wave.iter().all(|value| value.abs() < f32::EPSILON as f64) {
+            return Err(self.silent());
+        }
+        Ok(self.arrange(&wave))
+    }
+
+    /// Returns the wave as signed integers of `bits` bits like `sine_wave!` does for `i8`, `i16`,
+    /// `i24`, `i32` and `i64`.
+    ///
+    /// Rounding, full scale and dither are applied to the values. Any number of bits between 2 and
+    /// 64 is accepted, which is useful for e.g. 12-bit DACs.
+    pub fn generate_int(&self, bits: u32) -> Result<Vec<i64>, Error> {
+        if !(2..=64).contains(&bits) {
+            return Err(Error::InvalidBits(bits));
+        }
+        let wave = self.wave()?;
+        let max = i64::MAX as i128 >> (64 - bits);
+        let min = match self.full_scale {
+            FullScale::Symmetric => -max,
+            FullScale::Asymmetric => -max - 1,
+        };
+        if quantize(&wave, min, max, self.rounding, None)
+            .iter()
+            .all(|value| *value == 0)
+        {
+            return Err(self.silent());
+        }
+        let values = quantize(&wave, min, max, self.rounding, self.dither);
+        Ok(self
+            .arrange(&values)
+            .into_iter()
+            .map(|value| value as i64)
+            .collect())
+    }
+
+    fn silent(&self) -> Error {
+        Error::Silent {
+            rate: self.rate,
+            frequency: self.frequency,
+        }
+    }
+}
@@ -0,0 +1,278 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! WAV file export for listening to the waves.
+
+use crate::codegen::{SampleType, write_if_changed};
+use crate::{Error, WaveSpec};
+use std::io;
+use std::path::Path;
+
+/// `WAVE_FORMAT_PCM`
+const FORMAT_PCM: u16 = 1;
+
+/// `WAVE_FORMAT_IEEE_FLOAT`
+const FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// Format of the samples in a WAV file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WavFormat {
+    /// Sampling rate in Hz.
+    pub rate: u32,
+    /// Number of channels.
+    pub channels: u16,
+    /// Type of the samples.
+    pub ty: SampleType,
+}
+
+impl WavFormat {
+    /// Returns the number of bits per sample.
+    pub fn bits(&self) -> u16 {
+        self.ty.bits() as u16
+    }
+
+    /// Returns the number of bytes in a frame, i.e. a sample of every channel.
+    ///
+    /// Returns `None` if the frame is too large for a WAV file.
+    pub fn block_align(&self) -> Option<u16> {
+        self.channels.checked_mul(self.bits() / 8)
+    }
+
+    /// Returns the number of bytes per second, or `None` if it is too large for a WAV file.
+    fn byte_rate(&self) -> Option<u32> {
+        self.rate.checked_mul(self.block_align()? as u32)
+    }
+
+    fn format_tag(&self) -> u16 {
+        match self.ty.is_float() {
+            true => FORMAT_IEEE_FLOAT,
+            false => FORMAT_PCM,
+        }
+    }
+
+    /// Parses the header of a WAV file and returns the format and the data of the samples.
+    ///
+    /// Only the formats that [`Wav::encode`] writes are supported.
+    pub fn parse(wav: &[u8]) -> Result<(WavFormat, &[u8]), Error> {
+        let invalid = |message| Err(Error::InvalidWav(message));
+        if wav.len() < 12 || &wav[0..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+            return invalid("not a RIFF WAVE file");
+        }
+        let u16_at = |bytes: &[u8], i: usize| u16::from_le_bytes([bytes[i], bytes[i + 1]]);
+        let u32_at = |bytes: &[u8], i: usize| {
+            u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]])
+        };
+        let mut format = None;
+        let mut position = 12;
+        while position + 8 <= wav.len() {
+            let id = &wav[position..position + 4];
+            let size = u32_at(wav, position + 4) as usize;
+            let start = position + 8;
+            let Some(chunk) = start.checked_add(size).and_then(|end| wav.get(start..end)) else {
+                return invalid("truncated chunk");
+            };
+            match id {
+                b"fmt " => {
+                    if size < 16 {
+                        return invalid("too short `fmt ` chunk");
+                    }
+                    let ty = match (u16_at(chunk, 0), u16_at(chunk, 14)) {
+                        (FORMAT_PCM, 8) => SampleType::I8,
+                        (FORMAT_PCM, 16) => SampleType::I16,
+                        (FORMAT_PCM, 32) => SampleType::I32,
+                        (FORMAT_IEEE_FLOAT, 32) => SampleType::F32,
+                        (FORMAT_IEEE_FLOAT, 64) => SampleType::F64,
+                        _ => return invalid("unsupported sample format"),
+                    };
+                    let parsed = WavFormat {
+                        rate: u32_at(chunk, 4),
+                        channels: u16_at(chunk, 2),
+                        ty,
+                    };
+                    if parsed.channels == 0
+                        || parsed.block_align() != Some(u16_at(chunk, 12))
+                        || parsed.byte_rate() != Some(u32_at(chunk, 8))
+                    {
+                        return invalid("inconsistent `fmt ` chunk");
+                    }
+                    format = Some(parsed);
+                }
+                b"data" => {
+                    let Some(format) = format else {
+                        return invalid("`data` chunk before `fmt ` chunk");
+                    };
+                    // The block align was checked when parsing the `fmt ` chunk
+                    if size % format.block_align().unwrap() as usize != 0 {
+                        return invalid("partial frame in `data` chunk");
+                    }
+                    return Ok((format, chunk));
+                }
+                _ => (),
+            }
+            position = start + size + size % 2;
+        }
+        invalid("missing `data` chunk")
+    }
+}
+
+/// A wave to write as a WAV file.
+///
+/// The sampling rate of the file is the rate of the wave and the samples are the values of the
+/// wave as the given type, repeated in every channel.
+///
+/// # Example
+/// ```rust
+/// use sine_macro_core::codegen::SampleType;
+/// use sine_macro_core::{Wav, WavFormat, WaveSpec};
+///
+/// let spec = WaveSpec::new(440).with_rate(48_000).with_repeats(100);
+/// let wav = Wav::new(spec, SampleType::I16).with_channels(2).encode()?;
+/// let (format, data) = WavFormat::parse(&wav)?;
+/// assert_eq!(format.rate, 48_000);
+/// assert_eq!(format.channels, 2);
+/// assert_eq!(data.len(), 109 * 100 * 2 * 2);
+/// # Ok::<(), sine_macro_core::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Wav {
+    spec: WaveSpec,
+    ty: SampleType,
+    channels: u16,
+}
+
+impl Wav {
+    /// Returns a WAV file of `spec` with values of `ty` in one channel.
+    pub fn new(spec: WaveSpec, ty: SampleType) -> Self {
+        Wav {
+            spec,
+            ty,
+            channels: 1,
+        }
+    }
+
+    /// Sets the number of channels.
+    pub fn with_channels(mut self, channels: u16) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Returns the wave.
+    pub fn spec(&self) -> &WaveSpec {
+        &self.spec
+    }
+
+    /// Returns the format of the file.
+    pub fn format(&self) -> WavFormat {
+        WavFormat {
+            rate: self.spec.rate(),
+            channels: self.channels,
+            ty: self.ty,
+        }
+    }
+
+    /// Returns the samples of one channel as little endian bytes.
+    fn samples(&self) -> Result<Vec<u8>, Error> {
+        let spec = &self.spec;
+        Ok(match self.ty {
+            SampleType::I8 => spec
+                .generate_int(8)?
+                .into_iter()
+                .map(|value| (value + 128) as u8)
+                .collect(),
+            SampleType::I16 => spec
+                .generate_int(16)?
+                .into_iter()
+                .flat_map(|value| (value as i16).to_le_bytes())
+                .collect(),
+            SampleType::I32 => spec
+                .generate_int(32)?
+                .into_iter()
+                .flat_map(|value| (value as i32).to_le_bytes())
+                .collect(),
+            SampleType::F32 => spec
+                .generate()?
+                .into_iter()
+                .flat_map(|value| (value as f32).to_le_bytes())
+                .collect(),
+            SampleType::F64 => spec
+                .generate()?
+                .into_iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect(),
+            SampleType::I64 => return Err(Error::UnsupportedType(self.ty.name())),
+        })
+    }
+
+    /// Returns the wave as a WAV file.
+    ///
+    /// The values are the same as [`generate_int`](WaveSpec::generate_int) and
+    /// [`generate`](WaveSpec::generate) return for the type, except that 8-bit samples are
+    /// unsigned in WAV files, so they are offset by 128. `i64` is not supported.
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        let format = self.format();
+        if format.channels == 0 {
+            return Err(Error::NotPositive("channels"));
+        }
+        let (Some(block_align), Some(byte_rate)) = (format.block_align(), format.byte_rate())
+        else {
+            return Err(Error::InvalidWav("too many channels"));
+        };
+        let float = self.ty.is_float();
+        let fmt_size: u32 = if float { 18 } else { 16 };
+        let fact_size: u32 = if float { 12 } else { 0 };
+        // The sizes are checked before generating the samples, which could take all memory
+        let frames = self.spec.count()?;
+        let data_size = frames
+            .checked_mul(block_align as usize)
+            .and_then(|size| u32::try_from(size).ok());
+        // Chunks must have an even length, which only matters for odd numbers of 8-bit frames
+        let riff_size = data_size
+            .and_then(|size| size.checked_add(size % 2))
+            .and_then(|padded| (4 + 8 + fmt_size + fact_size + 8).checked_add(padded));
+        let (Some(data_size), Some(riff_size)) = (data_size, riff_size) else {
+            return Err(Error::InvalidWav("too large data"));
+        };
+        let samples = self.samples()?;
+        let size = format.bits() as usize / 8;
+        let mut output = Vec::with_capacity(riff_size as usize + 8);
+        output.extend_from_slice(b"RIFF");
+        output.extend_from_slice(&riff_size.to_le_bytes());
+        output.extend_from_slice(b"WAVE");
+        output.extend_from_slice(b"fmt ");
+        output.extend_from_slice(&fmt_size.to_le_bytes());
+        output.extend_from_slice(&format.format_tag().to_le_bytes());
+        output.extend_from_slice(&format.channels.to_le_bytes());
+        output.extend_from_slice(&format.rate.to_le_bytes());
+        output.extend_from_slice(&byte_rate.to_le_bytes());
+        output.extend_from_slice(&block_align.to_le_bytes());
+        output.extend_from_slice(&format.bits().to_le_bytes());
+        if float {
+            // No extension and the number of frames, which formats other than PCM must have
+            output.extend_from_slice(&0_u16.to_le_bytes());
+            output.extend_from_slice(b"fact");
+            output.extend_from_slice(&4_u32.to_le_bytes());
+            output.extend_from_slice(&(frames as u32).to_le_bytes());
+        }
+        output.extend_from_slice(b"data");
+        output.extend_from_slice(&data_size.to_le_bytes());
+        for sample in samples.chunks(size) {
+            for _ in 0..format.channels {
+                output.extend_from_slice(sample);
+            }
+        }
+        output.resize(output.len() + data_size as usize % 2, 0);
+        Ok(output)
+    }
+
+    /// Writes the wave as a WAV file to `path`.
+    ///
+    /// Errors of the spec and the format are returned as [`io::ErrorKind::InvalidInput`].
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let wav = self
+            .encode()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        write_if_changed(path.as_ref(), wav)
+    }
+}
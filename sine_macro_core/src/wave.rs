@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::Modulation;
+use sine_macro_const::sin_cos_turn;
+use std::f64::consts::PI;
+
+/// Returns the number of samples in a period of a wave of `frequency` at sampling `rate`.
+///
+/// This is `floor(rate / frequency)` and the same as `sine_macro_const::period`.
+///
+/// # Panics
+/// Panics if `frequency` is zero or higher than `rate`.
+pub fn period(frequency: u32, rate: u32) -> usize {
+    sine_macro_const::period(frequency, rate)
+}
+
+/// Samples one period of sine and cosine with the length rounded like with [`period`] and returns
+/// the values that `select` picks.
+fn get_wave(frequency: u32, rate: u32, select: fn((f64, f64)) -> f64) -> Vec<f64> {
+    let values = period(frequency, rate);
+    (0..values)
+        .map(|i| select(sin_cos_turn(i as u64, values as u64)))
+        .collect()
+}
+
+/// Returns one period of a sine wave in range `-1.0..=1.0`.
+///
+/// The values at every quarter of the period are exact, e.g. `0.0` at half of the period.
+///
+/// # Panics
+/// Panics if `frequency` is zero or higher than `rate`.
+pub fn sine_wave(frequency: u32, rate: u32) -> Vec<f64> {
+    get_wave(frequency, rate, |(sin, _)| sin)
+}
+
+/// Returns one period of a cosine wave in range `-1.0..=1.0`.
+///
+/// # Panics
+/// Panics if `frequency` is zero or higher than `rate`.
+pub fn cosine_wave(frequency: u32, rate: u32) -> Vec<f64> {
+    get_wave(frequency, rate, |(_, cos)| cos)
+}
+
+/// Returns one period of a sine wave in range `-1.0..=1.0` that lags `lag / phases` of the period
+/// behind [`sine_wave`].
+///
+/// The values are sampled like with [`sine_wave`], so the wave without a lag is the same.
+///
+/// # Panics
+/// Panics if `phases` is zero, or if `frequency` is zero or higher than `rate`.
+pub fn lagging_sine_wave(frequency: u32, rate: u32, lag: u32, phases: u32) -> Vec<f64> {
+    let values = period(frequency, rate) as u64;
+    let phases = phases as u64;
+    let lead = phases - lag as u64 % phases;
+    (0..values)
+        .map(|i| sin_cos_turn(i * phases + lead * values, values * phases).0)
+        .collect()
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+pub fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Returns the number of samples that contains whole periods of both `frequency` and `modulator`.
+fn get_loop_length(frequency: u32, modulator: u32, rate: u32) -> usize {
+    (rate / gcd(gcd(frequency, modulator), rate)) as usize
+}
+
+fn get_phase(sample: usize, frequency: u32, rate: u32) -> f64 {
+    let position = (sample as u64 * frequency as u64) % rate as u64;
+    PI * 2_f64 * position as f64 / rate as f64
+}
+
+/// Returns the shortest loop of a modulated wave in range `-1.0..=1.0`.
+///
+/// The loop spans whole periods of both the carrier at `frequency` and the modulator, which is
+/// `rate / gcd(rate, frequency, modulator)` samples.
+///
+/// # Panics
+/// Panics if `rate` is zero.
+pub fn modulated_wave(frequency: u32, rate: u32, modulation: Modulation) -> Vec<f64> {
+    let modulator = modulation.modulator();
+    let index = match modulation {
+        Modulation::Am { depth, .. } => depth as f64 / 100_f64,
+        Modulation::Fm { deviation, .. } => deviation as f64 / modulator as f64,
+    };
+    (0..get_loop_length(frequency, modulator, rate))
+        .map(|i| {
+            let (modulator, _) = sin_cos_turn(i as u64 * modulator as u64, rate as u64);
+            match modulation {
+                Modulation::Am { .. } => {
+                    let (carrier, _) = sin_cos_turn(i as u64 * frequency as u64, rate as u64);
+                    carrier * (1_f64 - index * (1_f64 - modulator) / 2_f64)
+                }
+                Modulation::Fm { .. } => (get_phase(i, frequency, rate) + index * modulator).sin(),
+            }
+        })
+        .collect()
+}
+
+/// Repeats `period` and returns `count` values of it starting from `skip`.
+///
+/// `count` defaults to the length of `period` times `repeats`, or one period if neither of them is
+/// given, like with the arguments of `sine_wave!` of the same names.
+pub fn arrange<T: Clone>(
+    period: &[T],
+    count: Option<usize>,
+    repeats: Option<usize>,
+    skip: usize,
+) -> Vec<T> {
+    let count = count.unwrap_or_else(|| period.len() * repeats.unwrap_or(1));
+    period
+        .iter()
+        .cycle()
+        .skip(skip)
+        .take(count)
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,140 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+#![cfg(feature = "cli")]
+
+use sine_macro_core::WavFormat;
+use sine_macro_core::codegen::SampleType;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sine_macro"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_rust() {
+    let output = stdout(&["-n", "BEEP", "frequency:", "25,", "rate:", "100"]);
+    assert_eq!(
+        output,
+        "/// 25 Hz sine wave at 100 Hz sampling rate.
+const BEEP: [i16; 4] = [
+    0, 32767, 0, -32767,
+];
+"
+    );
+}
+
+#[test]
+fn test_c() {
+    let output = stdout(&["--format", "c", "frequency: 25, rate: 100, type: i8"]);
+    assert_eq!(
+        output,
+        "/* 25 Hz sine wave at 100 Hz sampling rate. */
+#define WAVE_FREQUENCY 25
+#define WAVE_RATE 100
+#define WAVE_LEN 4
+static const int8_t WAVE[WAVE_LEN] = {
+    0, 127, 0, -127,
+};
+"
+    );
+}
+
+#[test]
+fn test_header() {
+    let output = stdout(&["-f", "header", "-n", "beep", "frequency: 25, rate: 100"]);
+    assert!(output.contains("#ifndef BEEP_H\n#define BEEP_H\n"));
+    assert!(output.contains("static const int16_t beep[beep_LEN] = {\n"));
+    assert!(output.ends_with("#endif /* BEEP_H */\n"));
+}
+
+#[test]
+fn test_csv() {
+    let output = stdout(&["-f", "csv", "frequency: 25, rate: 100, type: f32, len: 3"]);
+    assert_eq!(output, "index,value\n0,0.0\n1,1.0\n2,0.0\n");
+}
+
+#[test]
+fn test_plot() {
+    let output = stdout(&["-f", "plot", "frequency: 25, rate: 100"]);
+    let lines: Vec<_> = output.lines().collect();
+    assert_eq!(lines.len(), 17);
+    assert_eq!(lines[0], " 1 | *");
+    assert_eq!(lines[8], " 0 |*-*-");
+    assert_eq!(lines[16], "-1 |   *");
+}
+
+#[test]
+fn test_info() {
+    let output = stdout(&["-f", "info", "frequency: 440, rate: 48_000, repeats: 2"]);
+    assert_eq!(
+        output,
+        "frequency: 440 Hz
+effective frequency: 440.367 Hz
+rate: 48000 Hz
+type: i16
+samples: 218
+size: 436 bytes
+"
+    );
+}
+
+#[test]
+fn test_wav() {
+    let output = run(&["-f", "wav", "-c", "2", "frequency: 25, rate: 100, type: i8"]);
+    assert!(output.status.success());
+    let (format, data) = WavFormat::parse(&output.stdout).unwrap();
+    assert_eq!(format.rate, 100);
+    assert_eq!(format.channels, 2);
+    assert_eq!(format.ty, SampleType::I8);
+    assert_eq!(data, [128, 128, 255, 255, 128, 128, 1, 1]);
+    let path = std::env::temp_dir().join(format!("sine_macro_cli_{}.wav", std::process::id()));
+    let output = run(&[
+        "-f",
+        "wav",
+        "-o",
+        path.to_str().unwrap(),
+        "frequency: 25, rate: 100, type: f32",
+    ]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let wav = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let (format, data) = WavFormat::parse(&wav).unwrap();
+    assert_eq!(format.ty, SampleType::F32);
+    assert_eq!(&data[4..8], 1_f32.to_le_bytes());
+}
+
+#[test]
+fn test_errors() {
+    let output = run(&["frequency: 440, len: 10, repeats: 2"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: cannot define both `len` and `repeats`\n"
+    );
+    let output = run(&["-f", "svg", "frequency: 440"]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .starts_with("error: `--format` must be one of")
+    );
+    let output = run(&["-f", "wav", "frequency: 440, type: i64"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: `i64` cannot be written to WAV files\n"
+    );
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro_core::codegen::{Generator, Item, ItemKind, SampleType, Visibility};
+use sine_macro_core::{Error, FullScale, Modulation, WaveSpec};
+use std::fs;
+
+#[test]
+fn test_generate() {
+    let spec = WaveSpec::new(10).with_rate(100);
+    let code = Generator::new()
+        .item(Item::new("WAVE", spec.clone()))
+        .item(
+            Item::new("PUBLIC_WAVE", spec.clone().with_len(4))
+                .with_kind(ItemKind::Static)
+                .with_visibility(Visibility::Public)
+                .with_type(SampleType::I8),
+        )
+        .item(
+            Item::new("MUTABLE_WAVE", spec.with_repeats(2))
+                .with_kind(ItemKind::StaticMut)
+                .with_visibility(Visibility::Crate)
+                .with_type(SampleType::I32),
+        )
+        .generate()
+        .unwrap();
+    assert_eq!(
+        code,
+        "// Generated by sine_macro_core. Do not edit.
+
+/// 10 Hz sine wave at 100 Hz sampling rate.
+const WAVE: [i16; 10] = [
+    0, 19259, 31163, 31163, 19259, 0, -19259, -31163, -31163, -19259,
+];
+
+/// 10 Hz sine wave at 100 Hz sampling rate.
+pub static PUBLIC_WAVE: [i8; 4] = [
+    0, 74, 120, 120,
+];
+
+/// 10 Hz sine wave at 100 Hz sampling rate.
+pub(crate) static mut MUTABLE_WAVE: [i32; 20] = [
+    0, 1262259217, 2042378316, 2042378316, 1262259217, 0, -1262259217, -2042378316, -2042378316,
+    -1262259217, 0, 1262259217, 2042378316, 2042378316, 1262259217, 0, -1262259217, -2042378316,
+    -2042378316, -1262259217,
+];
+"
+    );
+}
+
+#[test]
+fn test_generate_float() {
+    let code = Generator::new()
+        .item(Item::new("WAVE", WaveSpec::new(25).with_rate(100)).with_type(SampleType::F32))
+        .generate()
+        .unwrap();
+    assert!(code.contains("const WAVE: [f32; 4] = [\n    0.0, 1.0, 0.0, -1.0,\n];\n"));
+    assert!(code.contains("#[allow(clippy::approx_constant)]\n"));
+}
+
+#[test]
+fn test_modulation_description() {
+    let spec = WaveSpec::new(10).with_rate(100);
+    let am = Item::new(
+        "AM",
+        spec.clone().with_modulation(Modulation::Am {
+            modulator: 5,
+            depth: 50,
+        }),
+    );
+    assert!(am.to_rust().unwrap().starts_with(
+        "/// 10 Hz carrier amplitude modulated by 5 Hz with 50% depth at 100 Hz sampling rate.\n"
+    ));
+    let fm = Item::new(
+        "FM",
+        spec.with_modulation(Modulation::Fm {
+            modulator: 5,
+            deviation: 3,
+        }),
+    );
+    assert!(fm.to_c().unwrap().starts_with(
+        "/* 10 Hz carrier frequency modulated by 5 Hz with 3 Hz deviation at 100 Hz sampling rate. */\n"
+    ));
+}
+
+#[test]
+fn test_generate_c_header() {
+    let spec = WaveSpec::new(25).with_rate(100);
+    let code = Generator::new()
+        .item(Item::new("WAVE", spec.clone()))
+        .item(
+            Item::new("MUTABLE_WAVE", spec.clone().with_len(2))
+                .with_kind(ItemKind::StaticMut)
+                .with_type(SampleType::F32),
+        )
+        .item(Item::new("LONG_WAVE", spec).with_type(SampleType::I64))
+        .generate_c_header("TABLES_H")
+        .unwrap();
+    assert_eq!(
+        code,
+        "/* Generated by sine_macro_core. Do not edit. */
+
+#ifndef TABLES_H
+#define TABLES_H
+
+#include <stdint.h>
+
+/* 25 Hz sine wave at 100 Hz sampling rate. */
+#define WAVE_FREQUENCY 25
+#define WAVE_RATE 100
+#define WAVE_LEN 4
+static const int16_t WAVE[WAVE_LEN] = {
+    0, 32767, 0, -32767,
+};
+
+/* 25 Hz sine wave at 100 Hz sampling rate. */
+#define MUTABLE_WAVE_FREQUENCY 25
+#define MUTABLE_WAVE_RATE 100
+#define MUTABLE_WAVE_LEN 2
+static float MUTABLE_WAVE[MUTABLE_WAVE_LEN] = {
+    0.0f, 1.0f,
+};
+
+/* 25 Hz sine wave at 100 Hz sampling rate. */
+#define LONG_WAVE_FREQUENCY 25
+#define LONG_WAVE_RATE 100
+#define LONG_WAVE_LEN 4
+static const int64_t LONG_WAVE[LONG_WAVE_LEN] = {
+    INT64_C(0), INT64_C(9223372036854775807), INT64_C(0), INT64_C(-9223372036854775807),
+};
+
+#endif /* TABLES_H */
+"
+    );
+}
+
+#[test]
+fn test_c_minimum() {
+    let spec = WaveSpec::new(25)
+        .with_rate(100)
+        .with_full_scale(FullScale::Asymmetric);
+    for (ty, min) in [
+        (SampleType::I8, "(-127 - 1)"),
+        (SampleType::I16, "(-32767 - 1)"),
+        (SampleType::I32, "(-2147483647 - 1)"),
+        (SampleType::I64, "(INT64_C(-9223372036854775807) - 1)"),
+    ] {
+        let item = Item::new("WAVE", spec.clone()).with_type(ty);
+        let c = item.to_c().unwrap();
+        let values = c.lines().nth(5).unwrap();
+        assert!(values.ends_with(&format!(", {},", min)), "{}", values);
+    }
+}
+
+#[test]
+fn test_write_c_header() {
+    let path = std::env::temp_dir().join(format!("sine_macro_core_{}.h", std::process::id()));
+    let generator = Generator::new().item(Item::new("WAVE", WaveSpec::new(441)));
+    generator.write_c_header(&path).unwrap();
+    let guard = format!("SINE_MACRO_CORE_{}_H", std::process::id());
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        generator.generate_c_header(&guard).unwrap()
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write() {
+    let path = std::env::temp_dir().join(format!("sine_macro_core_{}.rs", std::process::id()));
+    let generator = Generator::new().item(Item::new("WAVE", WaveSpec::new(441)));
+    generator.write(&path).unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        generator.generate().unwrap()
+    );
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_errors() {
+    for name in ["", "_", "1WAVE", "MY-WAVE"] {
+        let generator = Generator::new().item(Item::new(name, WaveSpec::new(441)));
+        assert_eq!(generator.generate(), Err(Error::InvalidName(name.into())));
+    }
+    let generator = Generator::new().item(Item::new("WAVE", WaveSpec::new(441).with_rate(100)));
+    assert_eq!(
+        generator.generate(),
+        Err(Error::AboveRate("frequency", 100))
+    );
+    let error = generator
+        .write(std::env::temp_dir().join("unused.rs"))
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,208 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro_core::codegen::SampleType;
+use sine_macro_core::{Error, Wav, WavFormat, WaveSpec};
+
+fn u16_at(bytes: &[u8], i: usize) -> u16 {
+    u16::from_le_bytes(bytes[i..i + 2].try_into().unwrap())
+}
+
+fn u32_at(bytes: &[u8], i: usize) -> u32 {
+    u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap())
+}
+
+#[test]
+fn test_pcm_header() {
+    let spec = WaveSpec::new(440).with_rate(48_000);
+    let wav = Wav::new(spec, SampleType::I16)
+        .with_channels(2)
+        .encode()
+        .unwrap();
+    assert_eq!(&wav[0..4], b"RIFF");
+    assert_eq!(u32_at(&wav, 4) as usize, wav.len() - 8);
+    assert_eq!(&wav[8..16], b"WAVEfmt ");
+    assert_eq!(u32_at(&wav, 16), 16);
+    assert_eq!(u16_at(&wav, 20), 1);
+    assert_eq!(u16_at(&wav, 22), 2);
+    assert_eq!(u32_at(&wav, 24), 48_000);
+    assert_eq!(u32_at(&wav, 28), 48_000 * 4);
+    assert_eq!(u16_at(&wav, 32), 4);
+    assert_eq!(u16_at(&wav, 34), 16);
+    assert_eq!(&wav[36..40], b"data");
+    assert_eq!(u32_at(&wav, 40), 109 * 4);
+    assert_eq!(wav.len(), 44 + 109 * 4);
+}
+
+#[test]
+fn test_round_trip() {
+    let spec = WaveSpec::new(440).with_rate(48_000).with_repeats(2);
+    let wav = Wav::new(spec.clone(), SampleType::I16)
+        .with_channels(3)
+        .encode()
+        .unwrap();
+    let (format, data) = WavFormat::parse(&wav).unwrap();
+    assert_eq!(
+        format,
+        WavFormat {
+            rate: 48_000,
+            channels: 3,
+            ty: SampleType::I16,
+        }
+    );
+    let expected = spec.generate_int(16).unwrap();
+    assert_eq!(data.len(), expected.len() * 6);
+    for (frame, value) in data.chunks(6).zip(expected) {
+        for channel in frame.chunks(2) {
+            assert_eq!(i16::from_le_bytes([channel[0], channel[1]]) as i64, value);
+        }
+    }
+
+    let spec = WaveSpec::new(1_000).with_rate(44_100).with_len(100);
+    for ty in [
+        SampleType::I8,
+        SampleType::I16,
+        SampleType::I32,
+        SampleType::F32,
+        SampleType::F64,
+    ] {
+        let wav = Wav::new(spec.clone(), ty);
+        let encoded = wav.encode().unwrap();
+        let (parsed, data) = WavFormat::parse(&encoded).unwrap();
+        assert_eq!(parsed, wav.format());
+        assert_eq!(parsed.bits() as u32, ty.bits());
+        assert_eq!(data.len(), 100 * ty.bits() as usize / 8);
+        assert_eq!(u32_at(&encoded, 4) as usize, encoded.len() - 8);
+    }
+}
+
+#[test]
+fn test_many_channels() {
+    let spec = WaveSpec::new(441);
+    let wav = Wav::new(spec, SampleType::I32)
+        .with_channels(4_096)
+        .encode()
+        .unwrap();
+    let (format, data) = WavFormat::parse(&wav).unwrap();
+    assert_eq!(format.channels, 4_096);
+    assert_eq!(format.block_align(), Some(16_384));
+    assert_eq!(data.len(), 100 * 16_384);
+}
+
+#[test]
+fn test_float() {
+    let spec = WaveSpec::new(25).with_rate(100);
+    let wav = Wav::new(spec.clone(), SampleType::F64).encode().unwrap();
+    assert_eq!(u32_at(&wav, 16), 18);
+    assert_eq!(u16_at(&wav, 20), 3);
+    assert_eq!(u16_at(&wav, 34), 64);
+    assert_eq!(&wav[38..42], b"fact");
+    assert_eq!(u32_at(&wav, 46), 4);
+    let (_, data) = WavFormat::parse(&wav).unwrap();
+    let values: Vec<f64> = data
+        .chunks(8)
+        .map(|value| f64::from_le_bytes(value.try_into().unwrap()))
+        .collect();
+    assert_eq!(values, spec.generate().unwrap());
+}
+
+#[test]
+fn test_8_bit() {
+    let spec = WaveSpec::new(25).with_rate(100).with_len(3);
+    let wav = Wav::new(spec, SampleType::I8).encode().unwrap();
+    let (_, data) = WavFormat::parse(&wav).unwrap();
+    assert_eq!(data, [128, 255, 128]);
+    // The odd-sized data chunk is padded
+    assert_eq!(wav.len(), 44 + 4);
+    assert_eq!(wav[47], 0);
+}
+
+#[test]
+fn test_write() {
+    let spec = WaveSpec::new(440);
+    let path = std::env::temp_dir().join(format!("sine_macro_wav_{}.wav", std::process::id()));
+    let wav = Wav::new(spec, SampleType::I16);
+    wav.write(&path).unwrap();
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, wav.encode().unwrap());
+}
+
+#[test]
+fn test_errors() {
+    let spec = WaveSpec::new(440);
+    assert_eq!(
+        Wav::new(spec.clone(), SampleType::I64).encode(),
+        Err(Error::UnsupportedType("i64"))
+    );
+    assert_eq!(
+        Wav::new(spec.clone(), SampleType::I16)
+            .with_channels(0)
+            .encode(),
+        Err(Error::NotPositive("channels"))
+    );
+    assert_eq!(
+        Wav::new(spec.clone(), SampleType::I32)
+            .with_channels(16_384)
+            .encode(),
+        Err(Error::InvalidWav("too many channels"))
+    );
+    assert_eq!(
+        Wav::new(WaveSpec::new(1).with_rate(4_000_000_000), SampleType::F64)
+            .with_channels(2)
+            .encode(),
+        Err(Error::InvalidWav("too many channels"))
+    );
+    assert_eq!(
+        Wav::new(spec.clone().with_len(1 << 31), SampleType::I16).encode(),
+        Err(Error::InvalidWav("too large data"))
+    );
+    // An odd number of bytes of data that fits in the size, but not with its padding
+    let low = WaveSpec::new(1).with_rate(100);
+    assert_eq!(
+        Wav::new(low.clone().with_len(u32::MAX as usize), SampleType::I8).encode(),
+        Err(Error::InvalidWav("too large data"))
+    );
+    assert_eq!(
+        Wav::new(low.with_len(u32::MAX as usize - 36), SampleType::I8).encode(),
+        Err(Error::InvalidWav("too large data"))
+    );
+    assert_eq!(
+        WavFormat::parse(b"RIFF\0\0\0\0AVI "),
+        Err(Error::InvalidWav("not a RIFF WAVE file"))
+    );
+    let wav = Wav::new(spec, SampleType::I16).encode().unwrap();
+    assert_eq!(
+        WavFormat::parse(&wav[..wav.len() - 1]),
+        Err(Error::InvalidWav("truncated chunk"))
+    );
+    let error = WavFormat::parse(&wav[..36]).unwrap_err();
+    assert_eq!(error.to_string(), "invalid WAV file: missing `data` chunk");
+}
+
+#[test]
+fn test_malformed_header() {
+    let spec = WaveSpec::new(440);
+    let wav = Wav::new(spec, SampleType::I32).encode().unwrap();
+    // Channels, byte rate and block align that overflow when they are multiplied
+    let mut malformed = wav.clone();
+    malformed[22..24].copy_from_slice(&u16::MAX.to_le_bytes());
+    assert_eq!(
+        WavFormat::parse(&malformed),
+        Err(Error::InvalidWav("inconsistent `fmt ` chunk"))
+    );
+    let mut malformed = wav.clone();
+    malformed[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        WavFormat::parse(&malformed),
+        Err(Error::InvalidWav("inconsistent `fmt ` chunk"))
+    );
+    let mut malformed = wav;
+    malformed[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        WavFormat::parse(&malformed),
+        Err(Error::InvalidWav("truncated chunk"))
+    );
+}
@@ -0,0 +1,239 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::items::Attrs;
+use crate::types::{Number, Type, parse_int};
+use crate::{check_fraction, check_signed, get_rate, parse_frequency, set_once};
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use std::num::NonZeroU32;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitInt, Result, Token};
+
+#[derive(Clone, Copy)]
+pub(crate) enum BiquadKind {
+    LowPass,
+    HighPass,
+    Peaking,
+    LowShelf,
+    HighShelf,
+    Notch,
+}
+
+impl Parse for BiquadKind {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "lowpass" => Ok(BiquadKind::LowPass),
+            "highpass" => Ok(BiquadKind::HighPass),
+            "peaking" => Ok(BiquadKind::Peaking),
+            "lowshelf" => Ok(BiquadKind::LowShelf),
+            "highshelf" => Ok(BiquadKind::HighShelf),
+            "notch" => Ok(BiquadKind::Notch),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `kind`, must be one of `lowpass`, `highpass`, `peaking`, `lowshelf`, `highshelf` and `notch`",
+            )),
+        }
+    }
+}
+
+enum BiquadAttrInput {
+    Kind(Ident, BiquadKind),
+    Rate(Ident, LitInt),
+    Frequency(Ident, LitInt),
+    Q(Ident, Number),
+    Gain(Ident, Number),
+    Fraction(Ident, LitInt),
+    Type(Token![type], Type),
+}
+
+impl Parse for BiquadAttrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![type]) {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            return Ok(BiquadAttrInput::Type(name, input.parse()?));
+        }
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        match name.to_string().as_ref() {
+            "kind" => Ok(BiquadAttrInput::Kind(name, input.parse()?)),
+            "rate" => Ok(BiquadAttrInput::Rate(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            "frequency" => Ok(BiquadAttrInput::Frequency(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            "q" => Ok(BiquadAttrInput::Q(name, input.parse()?)),
+            "gain" => Ok(BiquadAttrInput::Gain(name, input.parse()?)),
+            "fraction" => Ok(BiquadAttrInput::Fraction(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            _ => Err(Error::new(
+                name.span(),
+                "invalid identifier, must be one of `kind`, `rate`, `frequency`, `q`, `gain`, `fraction` and `type`",
+            )),
+        }
+    }
+}
+
+pub(crate) struct BiquadAttrs {
+    pub kind: BiquadKind,
+    pub rate: u32,
+    pub frequency: LitInt,
+    pub q: f64,
+    pub gain: f64,
+    pub fraction: Option<LitInt>,
+    pub ty: Option<Type>,
+}
+
+impl Parse for BiquadAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<BiquadAttrInput, Token![,]>::parse_terminated(input)?;
+        let mut kind = None;
+        let mut rate = None;
+        let mut frequency = None;
+        let mut q = None;
+        let mut gain = None;
+        let mut fraction = None;
+        let mut ty = None;
+        for attr in attrs {
+            match attr {
+                BiquadAttrInput::Kind(name, attr_value) => set_once(&mut kind, &name, attr_value)?,
+                BiquadAttrInput::Rate(name, attr_value) => {
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                    set_once(&mut rate, &name, attr_value)?
+                }
+                BiquadAttrInput::Frequency(name, attr_value) => {
+                    set_once(&mut frequency, &name, attr_value)?
+                }
+                BiquadAttrInput::Q(name, attr_value) => {
+                    if attr_value.value <= 0_f64 {
+                        return Err(Error::new_spanned(attr_value, "`q` must be positive"));
+                    }
+                    set_once(&mut q, &name, attr_value)?
+                }
+                BiquadAttrInput::Gain(name, attr_value) => set_once(&mut gain, &name, attr_value)?,
+                BiquadAttrInput::Fraction(name, attr_value) => {
+                    let _value: u32 = attr_value.base10_parse()?;
+                    set_once(&mut fraction, &name, attr_value)?
+                }
+                BiquadAttrInput::Type(name, attr_value) => set_once(&mut ty, &name, attr_value)?,
+            }
+        }
+        let Some(kind) = kind else {
+            return Err(Error::new(input.span(), "`kind` must be defined"));
+        };
+        let Some(frequency) = frequency else {
+            return Err(Error::new(input.span(), "`frequency` must be defined"));
+        };
+        let rate = get_rate(&rate).get();
+        parse_frequency("frequency", &frequency, rate)?;
+        let gain = match (kind, gain) {
+            (BiquadKind::Peaking | BiquadKind::LowShelf | BiquadKind::HighShelf, Some(gain)) => {
+                gain.value
+            }
+            (BiquadKind::Peaking | BiquadKind::LowShelf | BiquadKind::HighShelf, None) => {
+                return Err(Error::new(
+                    input.span(),
+                    "`gain` must be defined for `peaking`, `lowshelf` and `highshelf` filters",
+                ));
+            }
+            (_, Some(gain)) => {
+                return Err(Error::new_spanned(
+                    gain,
+                    "`gain` can be used only with `peaking`, `lowshelf` and `highshelf` filters",
+                ));
+            }
+            (_, None) => 0_f64,
+        };
+        check_fraction(&fraction, &ty)?;
+        check_signed(&ty)?;
+        Ok(BiquadAttrs {
+            kind,
+            rate,
+            frequency,
+            q: q.map(|q| q.value).unwrap_or(FRAC_1_SQRT_2),
+            gain,
+            fraction,
+            ty,
+        })
+    }
+}
+
+impl Attrs for BiquadAttrs {
+    const NAME: &'static str = "biquad";
+}
+
+/// Calculates `[b0, b1, b2, a1, a2]` normalized with `a0` as given by the Audio EQ Cookbook of
+/// Robert Bristow-Johnson.
+pub(crate) fn get_coefficients(attrs: &BiquadAttrs) -> [f64; 5] {
+    let frequency: f64 = attrs.frequency.base10_parse().unwrap();
+    let w0 = 2_f64 * PI * frequency / attrs.rate as f64;
+    let (sin, cos) = w0.sin_cos();
+    let alpha = sin / (2_f64 * attrs.q);
+    let a = 10_f64.powf(attrs.gain / 40_f64);
+    let [b0, b1, b2, a0, a1, a2] = match attrs.kind {
+        BiquadKind::LowPass => [
+            (1_f64 - cos) / 2_f64,
+            1_f64 - cos,
+            (1_f64 - cos) / 2_f64,
+            1_f64 + alpha,
+            -2_f64 * cos,
+            1_f64 - alpha,
+        ],
+        BiquadKind::HighPass => [
+            (1_f64 + cos) / 2_f64,
+            -(1_f64 + cos),
+            (1_f64 + cos) / 2_f64,
+            1_f64 + alpha,
+            -2_f64 * cos,
+            1_f64 - alpha,
+        ],
+        BiquadKind::Peaking => [
+            1_f64 + alpha * a,
+            -2_f64 * cos,
+            1_f64 - alpha * a,
+            1_f64 + alpha / a,
+            -2_f64 * cos,
+            1_f64 - alpha / a,
+        ],
+        BiquadKind::LowShelf => {
+            let shelf = 2_f64 * a.sqrt() * alpha;
+            [
+                a * ((a + 1_f64) - (a - 1_f64) * cos + shelf),
+                2_f64 * a * ((a - 1_f64) - (a + 1_f64) * cos),
+                a * ((a + 1_f64) - (a - 1_f64) * cos - shelf),
+                (a + 1_f64) + (a - 1_f64) * cos + shelf,
+                -2_f64 * ((a - 1_f64) + (a + 1_f64) * cos),
+                (a + 1_f64) + (a - 1_f64) * cos - shelf,
+            ]
+        }
+        BiquadKind::HighShelf => {
+            let shelf = 2_f64 * a.sqrt() * alpha;
+            [
+                a * ((a + 1_f64) + (a - 1_f64) * cos + shelf),
+                -2_f64 * a * ((a - 1_f64) + (a + 1_f64) * cos),
+                a * ((a + 1_f64) + (a - 1_f64) * cos - shelf),
+                (a + 1_f64) - (a - 1_f64) * cos + shelf,
+                2_f64 * ((a - 1_f64) - (a + 1_f64) * cos),
+                (a + 1_f64) - (a - 1_f64) * cos - shelf,
+            ]
+        }
+        BiquadKind::Notch => [
+            1_f64,
+            -2_f64 * cos,
+            1_f64,
+            1_f64 + alpha,
+            -2_f64 * cos,
+            1_f64 - alpha,
+        ],
+    };
+    [b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0]
+}
@@ -0,0 +1,294 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+//! Sine waves whose `frequency` or `rate` is a constant that only the compiler can resolve.
+//!
+//! The generated code computes the wave in const context, so the values are not known when the
+//! macro is expanded and only the arguments that can be checked or applied there are supported.
+
+use crate::types::helpers::{
+    Float as GetFloat, Ident as GetIdent, Literal as GetLiteral, Max as GetMax,
+};
+use crate::types::*;
+use crate::{DEFAULT_RATE, get_type, set_once};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::num::NonZeroUsize;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, LitInt, Result, Token, parse_quote};
+
+pub(crate) struct ConstSineWaveAttrs {
+    frequency: Expr,
+    rate: Option<Expr>,
+    len: Option<usize>,
+    repeats: Option<usize>,
+    skip: Option<usize>,
+    ty: Option<Type>,
+    rounding: Option<Rounding>,
+    full_scale: Option<FullScale>,
+}
+
+impl Parse for ConstSineWaveAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<AttrInput, Token![,]>::parse_terminated(input)?;
+        let mut frequency = None;
+        let mut rate = None;
+        let mut len: Option<LitInt> = None;
+        let mut repeats: Option<LitInt> = None;
+        let mut skip: Option<LitInt> = None;
+        let mut ty = None;
+        let mut rounding = None;
+        let mut full_scale = None;
+        for attr in attrs {
+            match attr {
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Frequency(attr_value),
+                    ..
+                }) => set_once(&mut frequency, &name, parse_quote!(#attr_value))?,
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Rate(attr_value),
+                    ..
+                }) => set_once(&mut rate, &name, parse_quote!(#attr_value))?,
+                AttrInput::Const(ConstAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => match name.to_string().as_ref() {
+                    "frequency" => set_once(&mut frequency, &name, attr_value)?,
+                    _ => set_once(&mut rate, &name, attr_value)?,
+                },
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Len(attr_value),
+                    ..
+                }) => {
+                    if repeats.is_some() {
+                        return Err(Error::new_spanned(
+                            name,
+                            sine_macro_core::Error::LenAndRepeats,
+                        ));
+                    }
+                    let _value: NonZeroUsize = attr_value.base10_parse()?;
+                    set_once(&mut len, &name, attr_value)?
+                }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Repeats(attr_value),
+                    ..
+                }) => {
+                    if len.is_some() {
+                        return Err(Error::new_spanned(
+                            name,
+                            sine_macro_core::Error::LenAndRepeats,
+                        ));
+                    }
+                    let value: usize = attr_value.base10_parse()?;
+                    if value == 0 {
+                        return Err(Error::new_spanned(
+                            attr_value,
+                            sine_macro_core::Error::NotPositive("repeats"),
+                        ));
+                    }
+                    set_once(&mut repeats, &name, attr_value)?
+                }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Skip(attr_value),
+                    ..
+                }) => {
+                    let _value: u32 = attr_value.base10_parse()?;
+                    set_once(&mut skip, &name, attr_value)?
+                }
+                AttrInput::Type(TypeAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut ty, &name, attr_value)?,
+                AttrInput::Rounding(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut rounding, &name, (name.clone(), attr_value))?,
+                AttrInput::FullScale(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut full_scale, &name, (name.clone(), attr_value))?,
+                AttrInput::Int(IntAttrInput { name, .. })
+                | AttrInput::Modulation(ChoiceAttrInput { name, .. })
+                | AttrInput::Quadrature(ChoiceAttrInput { name, .. })
+                | AttrInput::Shape(ChoiceAttrInput { name, .. })
+                | AttrInput::Layout(ChoiceAttrInput { name, .. })
+                | AttrInput::BitOrder(ChoiceAttrInput { name, .. })
+                | AttrInput::Bytes(ChoiceAttrInput { name, .. })
+                | AttrInput::Justify(ChoiceAttrInput { name, .. })
+                | AttrInput::Storage(ChoiceAttrInput { name, .. })
+                | AttrInput::Wrap(PathAttrInput { name, .. }) => {
+                    return Err(Error::new_spanned(
+                        &name,
+                        format_args!(
+                            "`{}` cannot be used when `frequency` or `rate` is a constant",
+                            name
+                        ),
+                    ));
+                }
+            }
+        }
+        let Some(frequency) = frequency else {
+            return Err(Error::new(
+                input.span(),
+                sine_macro_core::Error::Undefined("frequency"),
+            ));
+        };
+        let ty = get_type(ty, None, None)?;
+        if let Some(ty) = &ty {
+            if !matches!(
+                ty,
+                Type::I8(_)
+                    | Type::I16(_)
+                    | Type::I24(_)
+                    | Type::I32(_)
+                    | Type::I64(_)
+                    | Type::I128(_)
+                    | Type::F32(_)
+                    | Type::F64(_)
+            ) {
+                return Err(Error::new_spanned(
+                    ty.ident(),
+                    format_args!(
+                        "`{}` cannot be used when `frequency` or `rate` is a constant",
+                        ty.name()
+                    ),
+                ));
+            }
+        }
+        if ty.is_float() {
+            for (name, defined) in [
+                ("rounding", rounding.is_some()),
+                ("full_scale", full_scale.is_some()),
+            ] {
+                if defined {
+                    return Err(Error::new_spanned(
+                        ty.ident(),
+                        sine_macro_core::Error::FloatingPoint(name.into()),
+                    ));
+                }
+            }
+        }
+        let parse = |value: Option<LitInt>| value.map(|value| value.base10_parse().unwrap());
+        Ok(ConstSineWaveAttrs {
+            frequency,
+            rate,
+            len: parse(len),
+            repeats: parse(repeats),
+            skip: parse(skip),
+            ty,
+            rounding: rounding.map(|(_, value)| value),
+            full_scale: full_scale.map(|(_, value)| value),
+        })
+    }
+}
+
+impl ConstSineWaveAttrs {
+    /// Returns the constants that define the number of samples in a period and in the array.
+    fn lengths(&self) -> TokenStream {
+        let frequency = &self.frequency;
+        let rate = match &self.rate {
+            Some(rate) => quote!(#rate),
+            None => quote!(#DEFAULT_RATE),
+        };
+        let len = match (self.len, self.repeats) {
+            (Some(len), _) => quote!(#len),
+            (None, Some(repeats)) => quote!(__SINE_PERIOD * #repeats),
+            (None, None) => quote!(__SINE_PERIOD),
+        };
+        quote! {
+            const __SINE_FREQUENCY: u32 = #frequency;
+            const __SINE_RATE: u32 = #rate;
+            const __SINE_PERIOD: usize = ::sine_macro_const::period(__SINE_FREQUENCY, __SINE_RATE);
+            const __SINE_LEN: usize = #len;
+        }
+    }
+
+    /// Returns an expression that converts `value` in range `-1.0..=1.0` to the type.
+    fn convert(&self) -> TokenStream {
+        let ty = &self.ty;
+        if ty.is_float() {
+            let ident = ty.ident();
+            return quote!(value as #ident);
+        }
+        let min = ty.min(self.full_scale.unwrap_or(FullScale::Symmetric));
+        let max = ty.max();
+        let (neg_min, max_f64) = (-(min as f64), max as f64);
+        let round = match self.rounding.unwrap_or(Rounding::Truncate) {
+            Rounding::Truncate => quote!(value as i128),
+            Rounding::Nearest => quote! {{
+                let truncated = value as i128;
+                let fraction = value - truncated as f64;
+                if fraction >= 0.5 {
+                    truncated + 1
+                } else if fraction <= -0.5 {
+                    truncated - 1
+                } else {
+                    truncated
+                }
+            }},
+            Rounding::Floor => quote! {{
+                let truncated = value as i128;
+                if (truncated as f64) > value { truncated - 1 } else { truncated }
+            }},
+            Rounding::Ceil => quote! {{
+                let truncated = value as i128;
+                if (truncated as f64) < value { truncated + 1 } else { truncated }
+            }},
+        };
+        let ident = ty.ident();
+        quote! {{
+            let value = if value < 0_f64 { value * #neg_min } else { value * #max_f64 };
+            let value: i128 = #round;
+            let value = if value < #min { #min } else if value > #max { #max } else { value };
+            value as #ident
+        }}
+    }
+
+    /// Returns true if the values are floating point numbers.
+    pub(crate) fn is_float(&self) -> bool {
+        self.ty.is_float()
+    }
+
+    /// Returns the type of the array and an expression that computes it in const context.
+    pub(crate) fn expand(&self) -> (TokenStream, TokenStream) {
+        let lengths = self.lengths();
+        let path = self.ty.path();
+        let zero = match self.ty.is_float() {
+            true => self.ty.float_literal(0_f64),
+            false => self.ty.literal(0),
+        };
+        let skip = self.skip.unwrap_or(0);
+        let convert = self.convert();
+        let ty = quote!([#path; { #lengths __SINE_LEN }]);
+        let value = quote! {{
+            #lengths
+            const __SINE_VALUES: [#path; __SINE_LEN] = {
+                let mut values = [#zero; __SINE_LEN];
+                let mut i = 0;
+                while i < __SINE_LEN {
+                    let (value, _) = ::sine_macro_const::sin_cos_turn(
+                        (i + #skip) as u64,
+                        __SINE_PERIOD as u64,
+                    );
+                    values[i] = #convert;
+                    i += 1;
+                }
+                values
+            };
+            __SINE_VALUES
+        }};
+        (ty, value)
+    }
+}
@@ -0,0 +1,314 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::items::Attrs;
+use crate::types::{Number, Type, parse_int};
+use crate::window::{Symmetry, WindowKind, check_beta, get_window};
+use crate::{check_fraction, check_signed, get_rate, parse_frequency, set_once};
+use std::f64::consts::PI;
+use std::num::{NonZeroU32, NonZeroUsize};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitInt, Result, Token};
+
+#[derive(Clone, Copy)]
+pub(crate) enum FilterKind {
+    LowPass,
+    HighPass,
+    BandPass,
+    BandStop,
+}
+
+impl Parse for FilterKind {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "lowpass" => Ok(FilterKind::LowPass),
+            "highpass" => Ok(FilterKind::HighPass),
+            "bandpass" => Ok(FilterKind::BandPass),
+            "bandstop" => Ok(FilterKind::BandStop),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `kind`, must be one of `lowpass`, `highpass`, `bandpass` and `bandstop`",
+            )),
+        }
+    }
+}
+
+enum FirAttrInput {
+    Kind(Ident, FilterKind),
+    Rate(Ident, LitInt),
+    Cutoff(Ident, LitInt),
+    Low(Ident, LitInt),
+    High(Ident, LitInt),
+    Taps(Ident, LitInt),
+    Transition(Ident, LitInt),
+    Window(Ident, WindowKind),
+    Beta(Ident, Number),
+    Fraction(Ident, LitInt),
+    Type(Token![type], Type),
+}
+
+impl Parse for FirAttrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![type]) {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            return Ok(FirAttrInput::Type(name, input.parse()?));
+        }
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        match name.to_string().as_ref() {
+            "kind" => Ok(FirAttrInput::Kind(name, input.parse()?)),
+            "rate" => Ok(FirAttrInput::Rate(name.clone(), parse_int(input, &name)?)),
+            "cutoff" => Ok(FirAttrInput::Cutoff(name.clone(), parse_int(input, &name)?)),
+            "low" => Ok(FirAttrInput::Low(name.clone(), parse_int(input, &name)?)),
+            "high" => Ok(FirAttrInput::High(name.clone(), parse_int(input, &name)?)),
+            "taps" => Ok(FirAttrInput::Taps(name.clone(), parse_int(input, &name)?)),
+            "transition" => Ok(FirAttrInput::Transition(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            "window" => Ok(FirAttrInput::Window(name, input.parse()?)),
+            "beta" => Ok(FirAttrInput::Beta(name, input.parse()?)),
+            "fraction" => Ok(FirAttrInput::Fraction(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            _ => Err(Error::new(
+                name.span(),
+                "invalid identifier, must be one of `kind`, `rate`, `cutoff`, `low`, `high`, `taps`, `transition`, `window`, `beta`, `fraction` and `type`",
+            )),
+        }
+    }
+}
+
+/// Cutoff frequencies in Hz.
+pub(crate) enum Cutoff {
+    Single(u32),
+    Band(u32, u32),
+}
+
+pub(crate) struct FirAttrs {
+    pub kind: FilterKind,
+    pub rate: u32,
+    pub cutoff: Cutoff,
+    pub taps: LitInt,
+    pub window: WindowKind,
+    pub beta: Option<Number>,
+    pub fraction: Option<LitInt>,
+    pub ty: Option<Type>,
+}
+
+/// Returns the approximate width of the main lobe of the window in frequency bins.
+///
+/// The transition band of a windowed-sinc filter is about as wide as the main lobe of its window.
+fn get_main_lobe_width(window: WindowKind, beta: Option<f64>) -> f64 {
+    match window {
+        WindowKind::Rectangular => 2_f64,
+        WindowKind::Hann | WindowKind::Hamming => 4_f64,
+        WindowKind::Blackman => 6_f64,
+        WindowKind::BlackmanHarris => 8_f64,
+        WindowKind::FlatTop => 10_f64,
+        WindowKind::Kaiser => 2_f64 * (1_f64 + (beta.unwrap() / PI).powi(2)).sqrt(),
+    }
+}
+
+impl Parse for FirAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<FirAttrInput, Token![,]>::parse_terminated(input)?;
+        let mut kind = None;
+        let mut rate = None;
+        let mut cutoff = None;
+        let mut low = None;
+        let mut high = None;
+        let mut taps = None;
+        let mut transition = None;
+        let mut window = None;
+        let mut beta = None;
+        let mut fraction = None;
+        let mut ty = None;
+        for attr in attrs {
+            match attr {
+                FirAttrInput::Kind(name, attr_value) => set_once(&mut kind, &name, attr_value)?,
+                FirAttrInput::Rate(name, attr_value) => {
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                    set_once(&mut rate, &name, attr_value)?
+                }
+                FirAttrInput::Cutoff(name, attr_value) => set_once(&mut cutoff, &name, attr_value)?,
+                FirAttrInput::Low(name, attr_value) => set_once(&mut low, &name, attr_value)?,
+                FirAttrInput::High(name, attr_value) => set_once(&mut high, &name, attr_value)?,
+                FirAttrInput::Taps(name, attr_value) => {
+                    let _value: NonZeroUsize = attr_value.base10_parse()?;
+                    set_once(&mut taps, &name, attr_value)?
+                }
+                FirAttrInput::Transition(name, attr_value) => {
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                    set_once(&mut transition, &name, attr_value)?
+                }
+                FirAttrInput::Window(name, attr_value) => set_once(&mut window, &name, attr_value)?,
+                FirAttrInput::Beta(name, attr_value) => set_once(&mut beta, &name, attr_value)?,
+                FirAttrInput::Fraction(name, attr_value) => {
+                    let _value: u32 = attr_value.base10_parse()?;
+                    set_once(&mut fraction, &name, attr_value)?
+                }
+                FirAttrInput::Type(name, attr_value) => set_once(&mut ty, &name, attr_value)?,
+            }
+        }
+        let Some(kind) = kind else {
+            return Err(Error::new(input.span(), "`kind` must be defined"));
+        };
+        let Some(taps) = taps else {
+            return Err(Error::new(input.span(), "`taps` must be defined"));
+        };
+        let rate = get_rate(&rate).get();
+        let cutoff = match kind {
+            FilterKind::LowPass | FilterKind::HighPass => {
+                if let Some(value) = low.as_ref().or(high.as_ref()) {
+                    return Err(Error::new_spanned(
+                        value,
+                        "`low` and `high` can be used only with `bandpass` and `bandstop` filters",
+                    ));
+                }
+                let Some(cutoff) = cutoff else {
+                    return Err(Error::new(input.span(), "`cutoff` must be defined"));
+                };
+                Cutoff::Single(parse_frequency("cutoff", &cutoff, rate)?)
+            }
+            FilterKind::BandPass | FilterKind::BandStop => {
+                if let Some(cutoff) = cutoff {
+                    return Err(Error::new_spanned(
+                        cutoff,
+                        "`cutoff` can be used only with `lowpass` and `highpass` filters, use `low` and `high` instead",
+                    ));
+                }
+                let (Some(low), Some(high)) = (low, high) else {
+                    return Err(Error::new(input.span(), "`low` and `high` must be defined"));
+                };
+                let low_value = parse_frequency("low", &low, rate)?;
+                let high_value = parse_frequency("high", &high, rate)?;
+                if low_value >= high_value {
+                    return Err(Error::new_spanned(
+                        low,
+                        format_args!(
+                            "`low` should be less than `high`, which is {} Hz",
+                            high_value
+                        ),
+                    ));
+                }
+                Cutoff::Band(low_value, high_value)
+            }
+        };
+        let count: usize = taps.base10_parse().unwrap();
+        if matches!(kind, FilterKind::HighPass | FilterKind::BandStop) && count % 2 == 0 {
+            return Err(Error::new_spanned(
+                taps,
+                "`taps` must be odd for `highpass` and `bandstop` filters",
+            ));
+        }
+        let window = window.unwrap_or(WindowKind::Hamming);
+        check_beta(window, &beta, input.span())?;
+        if let Some(transition) = transition {
+            let width: u32 = transition.base10_parse().unwrap();
+            let main_lobe = get_main_lobe_width(window, beta.as_ref().map(|beta| beta.value));
+            let required = (main_lobe * rate as f64 / width as f64).ceil() as usize;
+            if count < required {
+                return Err(Error::new_spanned(
+                    taps,
+                    format_args!(
+                        "`taps` should be at least {} for `transition` of {} Hz with this window",
+                        required, width
+                    ),
+                ));
+            }
+        }
+        check_fraction(&fraction, &ty)?;
+        check_signed(&ty)?;
+        Ok(FirAttrs {
+            kind,
+            rate,
+            cutoff,
+            taps,
+            window,
+            beta,
+            fraction,
+            ty,
+        })
+    }
+}
+
+impl Attrs for FirAttrs {
+    const NAME: &'static str = "fir";
+}
+
+/// Ideal low-pass impulse response for normalized cutoff `cutoff` (cycles per sample) centered
+/// on `center`.
+fn sinc(n: usize, center: f64, cutoff: f64) -> f64 {
+    let x = n as f64 - center;
+    if x == 0_f64 {
+        2_f64 * cutoff
+    } else {
+        (2_f64 * PI * cutoff * x).sin() / (PI * x)
+    }
+}
+
+/// Designs windowed-sinc filter coefficients with unity gain in the pass band.
+pub(crate) fn get_coefficients(attrs: &FirAttrs) -> Vec<f64> {
+    let taps: usize = attrs.taps.base10_parse().unwrap();
+    let rate = attrs.rate as f64;
+    let center = (taps - 1) as f64 / 2_f64;
+    let window = get_window(
+        attrs.window,
+        attrs.beta.as_ref().map(|beta| beta.value),
+        Symmetry::Symmetric,
+        taps,
+    );
+    let impulse = |n| if n as f64 == center { 1_f64 } else { 0_f64 };
+    let (coefficients, frequency): (Vec<_>, _) = match (attrs.kind, &attrs.cutoff) {
+        (FilterKind::LowPass, Cutoff::Single(cutoff)) => (
+            (0..taps)
+                .map(|n| sinc(n, center, *cutoff as f64 / rate))
+                .collect(),
+            0_f64,
+        ),
+        (FilterKind::HighPass, Cutoff::Single(cutoff)) => (
+            (0..taps)
+                .map(|n| impulse(n) - sinc(n, center, *cutoff as f64 / rate))
+                .collect(),
+            0.5_f64,
+        ),
+        (FilterKind::BandPass, Cutoff::Band(low, high)) => (
+            (0..taps)
+                .map(|n| sinc(n, center, *high as f64 / rate) - sinc(n, center, *low as f64 / rate))
+                .collect(),
+            (*low + *high) as f64 / 2_f64 / rate,
+        ),
+        (FilterKind::BandStop, Cutoff::Band(low, high)) => (
+            (0..taps)
+                .map(|n| {
+                    impulse(n) - sinc(n, center, *high as f64 / rate)
+                        + sinc(n, center, *low as f64 / rate)
+                })
+                .collect(),
+            0_f64,
+        ),
+        _ => unreachable!(),
+    };
+    let coefficients: Vec<_> = coefficients
+        .into_iter()
+        .zip(window)
+        .map(|(coefficient, window)| coefficient * window)
+        .collect();
+    // Scale to unity gain at the center of the pass band
+    let gain: f64 = coefficients
+        .iter()
+        .enumerate()
+        .map(|(n, coefficient)| coefficient * (2_f64 * PI * frequency * (n as f64 - center)).cos())
+        .sum();
+    coefficients
+        .into_iter()
+        .map(|coefficient| coefficient / gain)
+        .collect()
+}
@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::items::Attrs;
+use crate::types::{Type, parse_int};
+use crate::{
+    check_fraction, check_frequency, check_signed, get_number_of_samples, get_rate, set_once,
+};
+use std::f64::consts::PI;
+use std::num::{NonZeroU32, NonZeroUsize};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Bracket;
+use syn::{Ident, LitInt, Result, Token, bracketed};
+
+/// List of frequencies in brackets.
+struct Frequencies {
+    _bracket: Bracket,
+    values: Punctuated<LitInt, Token![,]>,
+}
+
+impl Parse for Frequencies {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Frequencies {
+            _bracket: bracketed!(content in input),
+            values: Punctuated::parse_terminated_with(&content, |input| {
+                parse_int(input, "frequencies")
+            })?,
+        })
+    }
+}
+
+enum GoertzelAttrInput {
+    Rate(Ident, LitInt),
+    Block(Ident, LitInt),
+    Frequencies(Ident, Frequencies),
+    Fraction(Ident, LitInt),
+    Type(Token![type], Type),
+}
+
+impl Parse for GoertzelAttrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![type]) {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            return Ok(GoertzelAttrInput::Type(name, input.parse()?));
+        }
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        match name.to_string().as_ref() {
+            "rate" => Ok(GoertzelAttrInput::Rate(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            "block" => Ok(GoertzelAttrInput::Block(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            "frequencies" => Ok(GoertzelAttrInput::Frequencies(name, input.parse()?)),
+            "fraction" => Ok(GoertzelAttrInput::Fraction(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            _ => Err(Error::new(
+                name.span(),
+                "invalid identifier, must be one of `rate`, `block`, `frequencies`, `fraction` and `type`",
+            )),
+        }
+    }
+}
+
+pub(crate) struct GoertzelAttrs {
+    pub block: usize,
+    /// Frequency bin of each frequency.
+    pub bins: Vec<usize>,
+    pub fraction: Option<LitInt>,
+    pub ty: Option<Type>,
+}
+
+impl Parse for GoertzelAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<GoertzelAttrInput, Token![,]>::parse_terminated(input)?;
+        let mut rate = None;
+        let mut block = None;
+        let mut frequencies = None;
+        let mut fraction = None;
+        let mut ty = None;
+        for attr in attrs {
+            match attr {
+                GoertzelAttrInput::Rate(name, attr_value) => {
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                    set_once(&mut rate, &name, attr_value)?
+                }
+                GoertzelAttrInput::Block(name, attr_value) => {
+                    let _value: NonZeroUsize = attr_value.base10_parse()?;
+                    set_once(&mut block, &name, attr_value)?
+                }
+                GoertzelAttrInput::Frequencies(name, attr_value) => {
+                    if attr_value.values.is_empty() {
+                        return Err(Error::new_spanned(name, "`frequencies` must not be empty"));
+                    }
+                    set_once(&mut frequencies, &name, attr_value)?
+                }
+                GoertzelAttrInput::Fraction(name, attr_value) => {
+                    let _value: u32 = attr_value.base10_parse()?;
+                    set_once(&mut fraction, &name, attr_value)?
+                }
+                GoertzelAttrInput::Type(name, attr_value) => set_once(&mut ty, &name, attr_value)?,
+            }
+        }
+        let Some(block) = block else {
+            return Err(Error::new(input.span(), "`block` must be defined"));
+        };
+        let Some(frequencies) = frequencies else {
+            return Err(Error::new(input.span(), "`frequencies` must be defined"));
+        };
+        let block_value: usize = block.base10_parse().unwrap();
+        let rate_value = get_rate(&rate).get();
+        let mut bins = Vec::new();
+        for frequency in &frequencies.values {
+            let value = check_frequency(frequency, &rate)?;
+            let bin = get_bin(value.get(), rate_value, block_value);
+            if bin == 0 || bin * 2 > block_value {
+                return Err(Error::new_spanned(
+                    frequency,
+                    format_args!(
+                        "frequency falls to bin {} with `block` of {}, which should be between 1 and {}",
+                        bin,
+                        block,
+                        block_value / 2
+                    ),
+                ));
+            }
+            if let Some(index) = bins.iter().position(|other| *other == bin) {
+                return Err(Error::new_spanned(
+                    frequency,
+                    format_args!(
+                        "frequency falls to the same bin {} as {} Hz",
+                        bin,
+                        frequencies.values[index].base10_digits()
+                    ),
+                ));
+            }
+            bins.push(bin);
+        }
+        check_fraction(&fraction, &ty)?;
+        check_signed(&ty)?;
+        Ok(GoertzelAttrs {
+            block: block_value,
+            bins,
+            fraction,
+            ty,
+        })
+    }
+}
+
+impl Attrs for GoertzelAttrs {
+    const NAME: &'static str = "goertzel";
+}
+
+/// Returns the nearest frequency bin for the tone that `sine_wave!` generates for `frequency`.
+///
+/// The generated tone has a whole number of samples per period, so its frequency is
+/// `rate / floor(rate / frequency)` instead of `frequency`.
+fn get_bin(frequency: u32, rate: u32, block: usize) -> usize {
+    let period = get_number_of_samples(frequency, rate);
+    (block as f64 / period as f64).round() as usize
+}
+
+/// Returns the coefficient `2·cos(2πk/N)` for every bin.
+pub(crate) fn get_coefficients(attrs: &GoertzelAttrs) -> Vec<f64> {
+    attrs
+        .bins
+        .iter()
+        .map(|bin| 2_f64 * (2_f64 * PI * *bin as f64 / attrs.block as f64).cos())
+        .collect()
+}
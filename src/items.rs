@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use itertools::Itertools;
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::quote;
+use std::iter::repeat_n;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::token::Paren;
+use syn::{Ident, Result, StaticMutability, Token, Visibility, parenthesized};
+
+/// Arguments of a macro that can also be used with `const` and `static` items.
+pub(crate) trait Attrs: Parse {
+    /// The identifier that must be used in place of function name in items.
+    const NAME: &'static str;
+}
+
+fn parse_name<A: Attrs>(input: ParseStream) -> Result<Ident> {
+    let name: Ident = input.parse()?;
+    if name != A::NAME {
+        return Err(Error::new(
+            name.span(),
+            format_args!("the identifier must be `{}`", A::NAME),
+        ));
+    }
+    Ok(name)
+}
+
+pub(crate) struct Static<A> {
+    vis: Visibility,
+    _static_token: Token![static],
+    mutability: StaticMutability,
+    ident: Ident,
+    _eq_token: Token![=],
+    name: Ident,
+    _paren: Paren,
+    attrs: A,
+    _semi_token: Token![;],
+}
+
+impl<A: Attrs> Parse for Static<A> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Static {
+            vis: input.parse()?,
+            _static_token: input.parse()?,
+            mutability: input.parse()?,
+            ident: input.parse()?,
+            _eq_token: input.parse()?,
+            name: parse_name::<A>(input)?,
+            _paren: parenthesized!(content in input),
+            attrs: content.parse()?,
+            _semi_token: input.parse()?,
+        })
+    }
+}
+
+pub(crate) struct Const<A> {
+    vis: Visibility,
+    _const_token: Token![const],
+    ident: Ident,
+    _eq_token: Token![=],
+    name: Ident,
+    _paren: Paren,
+    attrs: A,
+    _semi_token: Token![;],
+}
+
+impl<A: Attrs> Parse for Const<A> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Const {
+            vis: input.parse()?,
+            _const_token: input.parse()?,
+            ident: input.parse()?,
+            _eq_token: input.parse()?,
+            name: parse_name::<A>(input)?,
+            _paren: parenthesized!(content in input),
+            attrs: content.parse()?,
+            _semi_token: input.parse()?,
+        })
+    }
+}
+
+pub(crate) enum Input<A> {
+    Local(A),
+    Static(Static<A>),
+    Const(Const<A>),
+}
+
+impl<A: Attrs> Parse for Input<A> {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![pub]) && (input.peek2(Token![static]) || input.peek2(Token![const])) {
+            if input.peek2(Token![static]) {
+                input.parse().map(Input::Static)
+            } else {
+                input.parse().map(Input::Const)
+            }
+        } else if input.peek(Token![static]) {
+            input.parse().map(Input::Static)
+        } else if input.peek(Token![const]) {
+            input.parse().map(Input::Const)
+        } else {
+            input.parse().map(Input::Local)
+        }
+    }
+}
+
+impl<A: Attrs> Input<A> {
+    pub(crate) fn get_attrs(&self) -> &A {
+        match self {
+            Self::Local(attrs) => attrs,
+            Self::Static(Static { attrs, .. }) => attrs,
+            Self::Const(Const { attrs, .. }) => attrs,
+        }
+    }
+
+    /// Returns `value` as is for local input or an item definition of type `ty` otherwise.
+    ///
+    /// Generated floating point values may be close to mathematical constants, e.g. `0.7071…`,
+    /// which clippy would complain about in the caller's code, so the lint is allowed for them if
+    /// `float` is true.
+    pub(crate) fn expand(
+        self,
+        ty: TokenStream,
+        value: TokenStream,
+        float: bool,
+    ) -> proc_macro::TokenStream {
+        let allow = match float {
+            true => quote!(#[allow(clippy::approx_constant)]),
+            false => TokenStream::new(),
+        };
+        match self {
+            Self::Local(_) if float => quote! {
+                {
+                    #allow
+                    let value: #ty = #value;
+                    value
+                }
+            }
+            .into(),
+            Self::Local(_) => value.into(),
+            Self::Static(item) => {
+                assert_eq!(item.name, A::NAME);
+                let vis = item.vis;
+                let mutability = item.mutability;
+                let ident = item.ident;
+                quote! {
+                    #allow
+                    #vis static #mutability #ident: #ty = #value;
+                }
+                .into()
+            }
+            Self::Const(item) => {
+                assert_eq!(item.name, A::NAME);
+                let vis = item.vis;
+                let ident = item.ident;
+                quote! {
+                    #allow
+                    #vis const #ident: #ty = #value;
+                }
+                .into()
+            }
+        }
+    }
+}
+
+/// Returns an array expression of `count` literals or other token trees.
+pub(crate) fn array(
+    values: impl IntoIterator<Item = impl Into<TokenTree>>,
+    count: usize,
+) -> TokenStream {
+    let tokens = TokenStream::from_iter(values.into_iter().map(Into::into).interleave(repeat_n(
+        TokenTree::from(Punct::new(',', Spacing::Alone)),
+        count.saturating_sub(1),
+    )));
+    TokenStream::from(TokenTree::from(Group::new(Delimiter::Bracket, tokens)))
+}
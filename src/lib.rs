@@ -24,29 +24,201 @@
 //! ```
 //!
 //! See the macro documentation for [more examples][crate::sine_wave!#arguments-and-examples].
+//!
+//! Seeded noise arrays can be generated similarly with [noise!], window functions with [window!]
+//! and filter coefficients with [fir!] and [biquad!]. Tones can be detected with the coefficients
+//! from [goertzel!] and FFTs computed with the tables from [twiddles!].
+//!
+//! Generic code, where the length of the array is a const generic parameter, can use
+//! [`sine_table`](https://docs.rs/sine_macro_const) of the companion crate `sine_macro_const`,
+//! which produces the same values as [sine_wave!] does with the default type.
+//!
+//! The waves can also be generated at run time, e.g. in build scripts, with `WaveSpec` of the
+//! [`sine_macro_core`](https://docs.rs/sine_macro_core) crate, which [sine_wave!] is built on.
+//!
+//! # Features
+//!
+//! - `fixed`: Allows signed fixed point types of the [`fixed`](https://docs.rs/fixed) crate, such
+//!   as `I1F15`, as `type`.
+//! - `half`: Allows `f16` of the [`half`](https://docs.rs/half) crate as `type`.
+//!
+//! The generated code refers to the types as `::fixed::types::I1F15` and `::half::f16`, so the
+//! crates must be dependencies of the crate that uses the macros.
 
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
 
 use itertools::Itertools;
-use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
-use quote::quote;
-use std::f64::consts::PI;
+use proc_macro2::{Delimiter, Group, Span, TokenTree};
+use quote::{ToTokens, quote};
+use sine_macro_core::{SplitMix64, WaveSpec, cosine_wave, lagging_sine_wave, quantize};
 use std::iter::repeat_n;
-use std::num::{NonZero, NonZeroU32, NonZeroUsize};
+use std::num::{NonZero, NonZeroU32, NonZeroU64, NonZeroUsize};
+use syn::Token;
 use syn::parse::{Error, Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Paren;
-use syn::{Ident, LitInt, Result, StaticMutability, Visibility, parse_macro_input};
-use syn::{Token, parenthesized};
+use syn::{LitInt, Result, parse_macro_input};
 
+mod biquad;
+mod constant;
+mod fir;
+mod goertzel;
+mod items;
+mod noise;
+mod pdm;
+mod twiddles;
 mod types;
-use crate::types::helpers::{Ident as GetIdent, Literal as GetLiteral, Max as GetMax};
+mod window;
+use crate::biquad::BiquadAttrs;
+use crate::constant::ConstSineWaveAttrs;
+use crate::fir::FirAttrs;
+use crate::goertzel::GoertzelAttrs;
+use crate::items::{Attrs, Input, array};
+use crate::noise::{NoiseAttrs, get_noise};
+use crate::pdm::get_pdm_words;
+use crate::twiddles::{Layout, TwiddlesAttrs, get_bit_reversal, get_twiddles};
+use crate::types::helpers::{
+    Bytes as GetBytes, Float as GetFloat, Fraction as GetFraction, Ident as GetIdent,
+    Literal as GetLiteral, Max as GetMax, Unsigned as GetUnsigned,
+};
 use crate::types::*;
+use crate::window::{Symmetry, WindowAttrs, get_window};
 
 const DEFAULT_RATE: u32 = 44_100;
 const DEFAULT_TYPE: &str = "i16";
 
+/// Returns the sampling rate or the default rate if `rate` is not defined.
+fn get_rate(rate: &Option<LitInt>) -> NonZeroU32 {
+    rate.as_ref()
+        .map(|rate| rate.base10_parse().unwrap())
+        .unwrap_or_else(|| NonZero::new(DEFAULT_RATE).unwrap())
+}
+
+/// Parses `frequency` and checks that it is less than `rate` or the default rate.
+fn check_frequency(frequency: &LitInt, rate: &Option<LitInt>) -> Result<NonZeroU32> {
+    let value: NonZeroU32 = frequency.base10_parse()?;
+    let rate = get_rate(rate);
+    if rate < value {
+        return Err(Error::new_spanned(
+            frequency,
+            sine_macro_core::Error::AboveRate("frequency", rate.get()),
+        ));
+    }
+    Ok(value)
+}
+
+/// Parses a frequency that must be below the Nyquist frequency of `rate`.
+fn parse_frequency(name: &str, value: &LitInt, rate: u32) -> Result<u32> {
+    let frequency: NonZeroU32 = value.base10_parse()?;
+    if frequency.get() as f64 >= rate as f64 / 2_f64 {
+        return Err(Error::new_spanned(
+            value,
+            format_args!(
+                "`{}` should be less than half of `rate`, which is {} Hz",
+                name, rate
+            ),
+        ));
+    }
+    Ok(frequency.get())
+}
+
+/// Stores `attr_value` to `value` unless the argument has already been defined.
+fn set_once<T>(value: &mut Option<T>, name: &impl ToTokens, attr_value: T) -> Result<()> {
+    if value.is_none() {
+        *value = Some(attr_value);
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            name,
+            sine_macro_core::Error::DefinedTwice(name.to_token_stream().to_string()),
+        ))
+    }
+}
+
+/// Checks that unsigned types are not used for signed values.
+fn check_signed(ty: &Option<Type>) -> Result<()> {
+    if ty.is_unsigned() {
+        return Err(Error::new_spanned(
+            ty.ident(),
+            "unsigned types can be used only with `pwm_top` and `pdm`",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that `fraction` is used only with integer types and fits in the type.
+fn check_fraction(fraction: &Option<LitInt>, ty: &Option<Type>) -> Result<()> {
+    if let Some(fraction) = fraction {
+        if ty.is_float() {
+            return Err(Error::new_spanned(
+                ty.ident(),
+                sine_macro_core::Error::FloatingPoint("fraction".into()),
+            ));
+        }
+        if ty.fraction().is_some() {
+            return Err(Error::new_spanned(
+                fraction,
+                "`fraction` cannot be used with fixed point types, which define their own fraction bits",
+            ));
+        }
+        let value: u32 = fraction.base10_parse().unwrap();
+        let bits = ty.bits();
+        if value >= bits {
+            return Err(Error::new_spanned(
+                fraction,
+                format_args!(
+                    "`fraction` should be less than the number of bits in `{}`, which is {}",
+                    ty.name(),
+                    bits
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the type of the values, which is given either as one of the supported types or as a
+/// path to another type whose values are `storage` values wrapped with `wrap`.
+fn get_type(
+    ty: Option<TypeInput>,
+    storage: Option<(syn::Ident, Type)>,
+    wrap: Option<(syn::Ident, syn::Path)>,
+) -> Result<Option<Type>> {
+    match (ty, storage, wrap) {
+        (Some(TypeInput::Path(path)), Some((_, storage)), wrap) => {
+            let wrap = wrap.map(|(_, wrap)| wrap).unwrap_or_else(|| path.clone());
+            Ok(Some(Type::Custom(Box::new(CustomType {
+                path,
+                storage,
+                wrap,
+            }))))
+        }
+        (Some(TypeInput::Path(path)), None, _) => Err(Error::new_spanned(
+            path,
+            sine_macro_core::Error::InvalidType {
+                name: "type",
+                storage: true,
+            },
+        )),
+        (Some(TypeInput::Type(ty)), storage, wrap) => {
+            if let Some(name) = storage
+                .as_ref()
+                .map(|(name, _)| name)
+                .or(wrap.as_ref().map(|(name, _)| name))
+            {
+                return Err(Error::new_spanned(
+                    name,
+                    format_args!("`{}` cannot be used with `{}`", name, ty.name()),
+                ));
+            }
+            Ok(Some(ty))
+        }
+        (None, Some((name, _)), _) => Err(Error::new_spanned(name, "`storage` requires `type`")),
+        (None, None, Some((name, _))) => Err(Error::new_spanned(name, "`wrap` requires `storage`")),
+        (None, None, None) => Ok(None),
+    }
+}
+
 struct SineWaveAttrs {
     frequency: LitInt,
     rate: Option<LitInt>,
@@ -54,6 +226,23 @@ struct SineWaveAttrs {
     repeats: Option<LitInt>,
     skip: Option<LitInt>,
     ty: Option<Type>,
+    modulation: Option<Modulation>,
+    modulator: Option<LitInt>,
+    depth: Option<LitInt>,
+    deviation: Option<LitInt>,
+    rounding: Option<Rounding>,
+    dither: Option<LitInt>,
+    full_scale: Option<FullScale>,
+    quadrature: Option<Quadrature>,
+    phases: Option<LitInt>,
+    shape: Option<Shape>,
+    layout: Option<PhaseLayout>,
+    pwm_top: Option<LitInt>,
+    dead_time: Option<LitInt>,
+    pdm: Option<LitInt>,
+    bit_order: Option<BitOrder>,
+    bytes: Option<ByteOrder>,
+    justify: Option<Justify>,
 }
 
 impl Parse for SineWaveAttrs {
@@ -65,6 +254,25 @@ impl Parse for SineWaveAttrs {
         let mut repeats = None;
         let mut skip = None;
         let mut ty = None;
+        let mut modulation = None;
+        let mut modulator = None;
+        let mut depth = None;
+        let mut deviation = None;
+        let mut rounding = None;
+        let mut dither = None;
+        let mut full_scale = None;
+        let mut quadrature = None;
+        let mut phases = None;
+        let mut shape = None;
+        let mut layout = None;
+        let mut pwm_top = None;
+        let mut dead_time = None;
+        let mut pdm = None;
+        let mut bit_order = None;
+        let mut bytes = None;
+        let mut justify = None;
+        let mut storage = None;
+        let mut wrap = None;
         for attr in attrs {
             match attr {
                 AttrInput::Int(IntAttrInput {
@@ -73,22 +281,17 @@ impl Parse for SineWaveAttrs {
                     ..
                 }) => {
                     if frequency.is_none() {
-                        let value: NonZeroU32 = attr_value.base10_parse()?;
-                        if let Some(rate) = &rate {
-                            let rate: NonZeroU32 = rate.base10_parse().unwrap();
-                            if rate < value {
-                                return Err(Error::new_spanned(
-                                    attr_value,
-                                    format_args!(
-                                        "`frequency` should be less than `rate`, which is {} Hz",
-                                        rate
-                                    ),
-                                ));
-                            }
+                        if rate.is_some() {
+                            check_frequency(&attr_value, &rate)?;
+                        } else {
+                            let _value: NonZeroU32 = attr_value.base10_parse()?;
                         }
                         frequency = Some(attr_value)
                     } else {
-                        return Err(Error::new_spanned(name, "`frequency` defined twice"));
+                        return Err(Error::new_spanned(
+                            &name,
+                            sine_macro_core::Error::DefinedTwice(name.to_string()),
+                        ));
                     }
                 }
                 AttrInput::Int(IntAttrInput {
@@ -103,16 +306,16 @@ impl Parse for SineWaveAttrs {
                             if frequency > value {
                                 return Err(Error::new_spanned(
                                     attr_value,
-                                    format_args!(
-                                        "`rate` should be more than `frequency`, which is {} Hz",
-                                        frequency
-                                    ),
+                                    sine_macro_core::Error::BelowFrequency(frequency.get()),
                                 ));
                             }
                         }
                         rate = Some(attr_value)
                     } else {
-                        return Err(Error::new_spanned(name, "`rate` defined twice"));
+                        return Err(Error::new_spanned(
+                            &name,
+                            sine_macro_core::Error::DefinedTwice(name.to_string()),
+                        ));
                     }
                 }
                 AttrInput::Int(IntAttrInput {
@@ -123,13 +326,16 @@ impl Parse for SineWaveAttrs {
                     if repeats.is_some() {
                         return Err(Error::new_spanned(
                             name,
-                            "cannot define both `len` and `repeats`",
+                            sine_macro_core::Error::LenAndRepeats,
                         ));
                     } else if len.is_none() {
                         let _value: NonZeroUsize = attr_value.base10_parse()?;
                         len = Some(attr_value)
                     } else {
-                        return Err(Error::new_spanned(name, "`len` defined twice"));
+                        return Err(Error::new_spanned(
+                            &name,
+                            sine_macro_core::Error::DefinedTwice(name.to_string()),
+                        ));
                     }
                 }
                 AttrInput::Int(IntAttrInput {
@@ -140,7 +346,7 @@ impl Parse for SineWaveAttrs {
                     if len.is_some() {
                         return Err(Error::new(
                             name.span(),
-                            "cannot define both `len` and `repeats`",
+                            sine_macro_core::Error::LenAndRepeats,
                         ));
                     } else if repeats.is_none() {
                         let value: usize = attr_value.base10_parse()?;
@@ -149,11 +355,14 @@ impl Parse for SineWaveAttrs {
                         } else {
                             return Err(Error::new_spanned(
                                 attr_value,
-                                "`repeats` must be positive",
+                                sine_macro_core::Error::NotPositive("repeats"),
                             ));
                         }
                     } else {
-                        return Err(Error::new_spanned(name, "`repeats` defined twice"));
+                        return Err(Error::new_spanned(
+                            &name,
+                            sine_macro_core::Error::DefinedTwice(name.to_string()),
+                        ));
                     }
                 }
                 AttrInput::Int(IntAttrInput {
@@ -165,31 +374,364 @@ impl Parse for SineWaveAttrs {
                         let _value: u32 = attr_value.base10_parse()?;
                         skip = Some(attr_value);
                     } else {
-                        return Err(Error::new_spanned(name, "`skip` defined twice"));
+                        return Err(Error::new_spanned(
+                            &name,
+                            sine_macro_core::Error::DefinedTwice(name.to_string()),
+                        ));
+                    }
+                }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Modulator(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut modulator, &name, attr_value.clone())?;
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Depth(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut depth, &name, attr_value.clone())?;
+                    let value: u32 = attr_value.base10_parse()?;
+                    if value == 0 || value > 100 {
+                        return Err(Error::new_spanned(
+                            attr_value,
+                            sine_macro_core::Error::InvalidDepth,
+                        ));
                     }
                 }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Deviation(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut deviation, &name, attr_value.clone())?;
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                }
                 AttrInput::Type(TypeAttrInput {
                     name,
                     value: attr_value,
                     ..
+                }) => set_once(&mut ty, &name, attr_value)?,
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Dither(attr_value),
+                    ..
                 }) => {
-                    if ty.is_none() {
-                        ty = Some(attr_value)
-                    } else {
-                        return Err(Error::new_spanned(name, "`type` defined twice"));
+                    set_once(&mut dither, &name, attr_value.clone())?;
+                    let _value: u64 = attr_value.base10_parse()?;
+                }
+                AttrInput::Modulation(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut modulation, &name, attr_value)?,
+                AttrInput::Rounding(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut rounding, &name, attr_value)?,
+                AttrInput::FullScale(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut full_scale, &name, attr_value)?,
+                AttrInput::Quadrature(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut quadrature, &name, (name.clone(), attr_value))?,
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Phases(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut phases, &name, attr_value.clone())?;
+                    let value: u32 = attr_value.base10_parse()?;
+                    if value < 2 {
+                        return Err(Error::new_spanned(
+                            attr_value,
+                            "`phases` must be at least 2",
+                        ));
                     }
                 }
+                AttrInput::Shape(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut shape, &name, (name.clone(), attr_value))?,
+                AttrInput::Layout(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut layout, &name, (name.clone(), attr_value))?,
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::PwmTop(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut pwm_top, &name, attr_value.clone())?;
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::DeadTime(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut dead_time, &name, attr_value.clone())?;
+                    let _value: u32 = attr_value.base10_parse()?;
+                }
+                AttrInput::Int(IntAttrInput {
+                    name,
+                    value: Int::Pdm(attr_value),
+                    ..
+                }) => {
+                    set_once(&mut pdm, &name, attr_value.clone())?;
+                    let _value: NonZeroU32 = attr_value.base10_parse()?;
+                }
+                AttrInput::BitOrder(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut bit_order, &name, (name.clone(), attr_value))?,
+                AttrInput::Bytes(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut bytes, &name, attr_value)?,
+                AttrInput::Justify(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut justify, &name, (name.clone(), attr_value))?,
+                AttrInput::Storage(ChoiceAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut storage, &name, (name.clone(), attr_value))?,
+                AttrInput::Wrap(PathAttrInput {
+                    name,
+                    value: attr_value,
+                    ..
+                }) => set_once(&mut wrap, &name, (name.clone(), attr_value))?,
+                AttrInput::Const(ConstAttrInput { name, value, .. }) => {
+                    return Err(Error::new_spanned(
+                        value,
+                        format_args!("`{}` must be an integer literal", name),
+                    ));
+                }
             };
         }
+        let ty = get_type(ty, storage, wrap)?;
         if let Some(frequency) = frequency {
             if rate.is_none() {
-                let value: NonZeroU32 = frequency.base10_parse().unwrap();
-                if DEFAULT_RATE < value.get() {
+                check_frequency(&frequency, &rate)?;
+            }
+            if let (Some((name, _)), Some(_)) = (&quadrature, &modulation) {
+                return Err(Error::new_spanned(
+                    name,
+                    "`quadrature` cannot be used with `modulation`",
+                ));
+            }
+            if let Some(phases) = &phases {
+                if let Some(modulation) = &modulation {
+                    return Err(Error::new_spanned(
+                        modulation.ident(),
+                        "`modulation` cannot be used with `phases`",
+                    ));
+                }
+                if let Some((name, _)) = &quadrature {
+                    return Err(Error::new_spanned(
+                        name,
+                        "`quadrature` cannot be used with `phases`",
+                    ));
+                }
+                let value: u32 = phases.base10_parse().unwrap();
+                if let Some((name, Shape::ThirdHarmonic | Shape::SpaceVector)) = &shape {
+                    if value != 3 {
+                        return Err(Error::new_spanned(
+                            name,
+                            "`shape` other than `sine` requires `phases: 3`",
+                        ));
+                    }
+                }
+            } else if let Some(name) = shape
+                .as_ref()
+                .map(|(name, _)| name)
+                .or(layout.as_ref().map(|(name, _)| name))
+            {
+                return Err(Error::new_spanned(
+                    name,
+                    format_args!("`{}` requires `phases`", name),
+                ));
+            }
+            match &modulation {
+                Some(modulation) => {
+                    let Some(modulator) = &modulator else {
+                        return Err(Error::new_spanned(
+                            modulation.ident(),
+                            sine_macro_core::Error::MissingModulator,
+                        ));
+                    };
+                    let value: u32 = modulator.base10_parse().unwrap();
+                    let rate_value = get_rate(&rate).get();
+                    if rate_value <= value {
+                        return Err(Error::new_spanned(
+                            modulator,
+                            sine_macro_core::Error::AboveRate("modulator", rate_value),
+                        ));
+                    }
+                    match modulation {
+                        Modulation::Am(_) => {
+                            if let Some(deviation) = &deviation {
+                                return Err(Error::new_spanned(
+                                    deviation,
+                                    sine_macro_core::Error::WrongModulation("deviation", "fm"),
+                                ));
+                            }
+                        }
+                        Modulation::Fm(ident) => {
+                            if let Some(depth) = &depth {
+                                return Err(Error::new_spanned(
+                                    depth,
+                                    sine_macro_core::Error::WrongModulation("depth", "am"),
+                                ));
+                            }
+                            let Some(deviation) = &deviation else {
+                                return Err(Error::new_spanned(
+                                    ident,
+                                    sine_macro_core::Error::MissingDeviation,
+                                ));
+                            };
+                            let value: u32 = deviation.base10_parse().unwrap();
+                            let frequency: u32 = frequency.base10_parse().unwrap();
+                            if frequency <= value {
+                                return Err(Error::new_spanned(
+                                    deviation,
+                                    sine_macro_core::Error::InvalidDeviation(frequency),
+                                ));
+                            }
+                        }
+                    }
+                }
+                None => {
+                    for (name, value) in [
+                        ("modulator", &modulator),
+                        ("depth", &depth),
+                        ("deviation", &deviation),
+                    ] {
+                        if let Some(value) = value {
+                            return Err(Error::new_spanned(
+                                value,
+                                sine_macro_core::Error::RequiresModulation(name.into()),
+                            ));
+                        }
+                    }
+                }
+            }
+            if let Some(pdm) = &pdm {
+                for (name, defined) in [
+                    ("modulation", modulation.is_some()),
+                    ("quadrature", quadrature.is_some()),
+                    ("phases", phases.is_some()),
+                    ("pwm_top", pwm_top.is_some()),
+                    ("rounding", rounding.is_some()),
+                    ("dither", dither.is_some()),
+                    ("full_scale", full_scale.is_some()),
+                ] {
+                    if defined {
+                        return Err(Error::new_spanned(
+                            pdm,
+                            format_args!("`{}` cannot be used with `pdm`", name),
+                        ));
+                    }
+                }
+                if !ty.is_unsigned() {
+                    return Err(Error::new_spanned(
+                        pdm,
+                        "`pdm` requires an unsigned type, one of `u8`, `u16` and `u32`",
+                    ));
+                }
+            } else if let Some((name, _)) = &bit_order {
+                return Err(Error::new_spanned(name, "`bit_order` requires `pdm`"));
+            }
+            if let Some((name, _)) = &justify {
+                if !matches!(ty, Some(Type::I24(_))) {
+                    return Err(Error::new_spanned(
+                        name,
+                        "`justify` can be used only with `i24`",
+                    ));
+                }
+            }
+            if ty.is_float() {
+                for (name, defined) in [
+                    ("rounding", rounding.is_some()),
+                    ("dither", dither.is_some()),
+                    ("full_scale", full_scale.is_some()),
+                    ("pwm_top", pwm_top.is_some()),
+                ] {
+                    if defined {
+                        return Err(Error::new_spanned(
+                            ty.ident(),
+                            sine_macro_core::Error::FloatingPoint(name.into()),
+                        ));
+                    }
+                }
+            }
+            if let Some(pwm_top) = &pwm_top {
+                if ty.fraction().is_some() {
+                    return Err(Error::new_spanned(
+                        ty.ident(),
+                        "`pwm_top` cannot be used with fixed point types",
+                    ));
+                }
+                let value: i128 = pwm_top.base10_parse().unwrap();
+                if value > ty.max() {
                     return Err(Error::new_spanned(
-                        frequency,
-                        "`frequency` should be less than `rate`, which is 44100 Hz",
+                        pwm_top,
+                        format_args!(
+                            "`pwm_top` does not fit in `{}`, whose maximum is {}",
+                            ty.name(),
+                            ty.max()
+                        ),
                     ));
                 }
+                if let Some(modulation) = &modulation {
+                    return Err(Error::new_spanned(
+                        modulation.ident(),
+                        "`modulation` cannot be used with `pwm_top`",
+                    ));
+                }
+                if full_scale.is_some() {
+                    return Err(Error::new_spanned(
+                        pwm_top,
+                        "`full_scale` cannot be used with `pwm_top`",
+                    ));
+                }
+                if let Some(dead_time) = &dead_time {
+                    let dead_time_value: i128 = dead_time.base10_parse().unwrap();
+                    if dead_time_value * 2 >= value {
+                        return Err(Error::new_spanned(
+                            dead_time,
+                            format_args!(
+                                "`dead_time` must be less than half of `pwm_top`, which is {}",
+                                value
+                            ),
+                        ));
+                    }
+                }
+            } else {
+                if let Some(dead_time) = &dead_time {
+                    return Err(Error::new_spanned(
+                        dead_time,
+                        "`dead_time` requires `pwm_top`",
+                    ));
+                }
+                if pdm.is_none() {
+                    check_signed(&ty)?;
+                }
             }
             Ok(SineWaveAttrs {
                 frequency,
@@ -198,125 +740,273 @@ impl Parse for SineWaveAttrs {
                 repeats,
                 skip,
                 ty,
+                modulation,
+                modulator,
+                depth,
+                deviation,
+                rounding,
+                dither,
+                full_scale,
+                quadrature: quadrature.map(|(_, value)| value),
+                phases,
+                shape: shape.map(|(_, value)| value),
+                layout: layout.map(|(_, value)| value),
+                pwm_top,
+                dead_time,
+                pdm,
+                bit_order: bit_order.map(|(_, value)| value),
+                bytes,
+                justify: justify.map(|(_, value)| value),
             })
         } else {
-            Err(Error::new(input.span(), "`frequency` must be defined"))
+            Err(Error::new(
+                input.span(),
+                sine_macro_core::Error::Undefined("frequency"),
+            ))
         }
     }
 }
 
-struct Static {
-    vis: Visibility,
-    _static_token: Token![static],
-    mutability: StaticMutability,
-    ident: Ident,
-    _eq_token: Token![=],
-    name: Ident,
-    _paren: Paren,
-    attrs: SineWaveAttrs,
-    _semi_token: Token![;],
+/// Value of a sample before it is converted to a literal or bytes of the type.
+#[derive(Clone, Copy)]
+enum Sample {
+    Int(i128),
+    Float(f64),
 }
 
-impl Parse for Static {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let content;
-        Ok(Static {
-            vis: input.parse()?,
-            _static_token: input.parse()?,
-            mutability: input.parse()?,
-            ident: input.parse()?,
-            _eq_token: input.parse()?,
-            name: {
-                let name: Ident = input.parse()?;
-                if name != "sine_wave" {
-                    return Err(Error::new(
-                        name.span(),
-                        "the identifier must be `sine_wave`",
-                    ));
-                }
-                name
-            },
-            _paren: parenthesized!(content in input),
-            attrs: content.parse()?,
-            _semi_token: input.parse()?,
-        })
+impl Sample {
+    fn literal(self, ty: &Option<Type>) -> TokenTree {
+        match self {
+            Sample::Int(value) => ty.literal(value),
+            Sample::Float(value) => ty.float_literal(value),
+        }
+    }
+
+    fn bytes(self, ty: &Option<Type>, order: ByteOrder) -> Vec<u8> {
+        match self {
+            Sample::Int(value) => ty.bytes(value, order),
+            Sample::Float(value) => ty.float_bytes(value, order),
+        }
     }
 }
 
-struct Const {
-    vis: Visibility,
-    _const_token: Token![const],
-    ident: Ident,
-    _eq_token: Token![=],
-    name: Ident,
-    _paren: Paren,
-    attrs: SineWaveAttrs,
-    _semi_token: Token![;],
+/// Converts the values to samples of the type, scaling them to integers for integer types.
+fn to_samples(
+    ty: &Option<Type>,
+    values: &[f64],
+    min: i128,
+    rounding: Rounding,
+    dither: Option<u64>,
+) -> Vec<Sample> {
+    if ty.is_float() {
+        values.iter().map(|value| Sample::Float(*value)).collect()
+    } else {
+        quantize(values, min, ty.max(), rounding.into(), dither)
+            .into_iter()
+            .map(Sample::Int)
+            .collect()
+    }
 }
 
-impl Parse for Const {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let content;
-        Ok(Const {
-            vis: input.parse()?,
-            _const_token: input.parse()?,
-            ident: input.parse()?,
-            _eq_token: input.parse()?,
-            name: {
-                let name: Ident = input.parse()?;
-                if name != "sine_wave" {
-                    return Err(Error::new(
-                        name.span(),
-                        "the identifier must be `sine_wave`",
-                    ));
-                }
-                name
-            },
-            _paren: parenthesized!(content in input),
-            attrs: content.parse()?,
-            _semi_token: input.parse()?,
+/// Converts the values to literals of the type, scaling them to integers for integer types.
+fn to_literals(
+    ty: &Option<Type>,
+    values: &[f64],
+    min: i128,
+    rounding: Rounding,
+    dither: Option<u64>,
+) -> Vec<TokenTree> {
+    to_samples(ty, values, min, rounding, dither)
+        .into_iter()
+        .map(|sample| sample.literal(ty))
+        .collect()
+}
+
+/// Converts the values to fixed point numbers with `fraction` fraction bits.
+///
+/// Returns `None` if any of the values does not fit in the type.
+fn to_fixed_point(ty: &Option<Type>, values: &[f64], fraction: u32) -> Option<Vec<i128>> {
+    // The whole range of the underlying integer is used, also for fixed point types
+    let bits = ty.bits();
+    let (min, max) = (
+        i128::MIN >> (i128::BITS - bits),
+        i128::MAX >> (i128::BITS - bits),
+    );
+    // The upper bound is exclusive as `MAX` of 64-bit and wider types rounds up to a power of two
+    let range = min as f64..max as f64 + 1_f64;
+    let scale = 2_f64.powi(fraction as i32);
+    values
+        .iter()
+        .map(|value| {
+            let value = (value * scale).round();
+            range.contains(&value).then_some(value as i128)
         })
+        .collect()
+}
+
+/// Converts filter coefficients to literals of the type.
+///
+/// Integer types are treated as fixed point numbers with `fraction` fraction bits, which defaults
+/// to `headroom` bits less than the size of the type. Fixed point types use their own fraction
+/// bits. It is an error spanned at `span` if the coefficients do not fit in the type.
+fn to_coefficient_literals(
+    ty: &Option<Type>,
+    values: &[f64],
+    fraction: &Option<LitInt>,
+    headroom: u32,
+    span: Span,
+) -> Result<Vec<TokenTree>> {
+    if ty.is_float() {
+        return Ok(values
+            .iter()
+            .map(|value| ty.float_literal(*value))
+            .collect());
     }
+    let fraction = fraction
+        .as_ref()
+        .map(|fraction| fraction.base10_parse().unwrap())
+        .or(ty.fraction())
+        .unwrap_or(ty.bits() - headroom);
+    let Some(values) = to_fixed_point(ty, values, fraction) else {
+        return Err(Error::new(
+            span,
+            format_args!(
+                "coefficients do not fit in `{}` with {} fraction bits",
+                ty.name(),
+                fraction
+            ),
+        ));
+    };
+    Ok(values.into_iter().map(|value| ty.literal(value)).collect())
 }
 
-enum SineWaveInput {
-    Local(SineWaveAttrs),
-    Static(Static),
-    Const(Const),
+/// Converts the values to PWM compare values in range `0..=top`.
+///
+/// The values are clamped to `dead_time..=top - dead_time` afterwards, so that neither of the
+/// switches is driven for less than `dead_time` timer ticks.
+fn to_pwm_samples(
+    values: &[f64],
+    top: i128,
+    dead_time: i128,
+    rounding: Rounding,
+    dither: Option<u64>,
+) -> Vec<Sample> {
+    let duty: Vec<_> = values.iter().map(|value| (value + 1_f64) / 2_f64).collect();
+    quantize(&duty, 0, top, rounding.into(), dither)
+        .into_iter()
+        .map(|value| Sample::Int(value.clamp(dead_time, top - dead_time)))
+        .collect()
 }
 
-impl Parse for SineWaveInput {
-    fn parse(input: ParseStream) -> Result<Self> {
-        if input.peek(Token![pub]) && (input.peek2(Token![static]) || input.peek2(Token![const])) {
-            if input.peek2(Token![static]) {
-                input.parse().map(SineWaveInput::Static)
-            } else {
-                input.parse().map(SineWaveInput::Const)
-            }
-        } else if input.peek(Token![static]) {
-            input.parse().map(SineWaveInput::Static)
-        } else if input.peek(Token![const]) {
-            input.parse().map(SineWaveInput::Const)
-        } else {
-            input.parse().map(SineWaveInput::Local)
+/// Returns true if all values would be zeros with the type.
+fn is_silent(ty: &Option<Type>, values: &[f64], min: i128, rounding: Rounding) -> bool {
+    if ty.is_float() {
+        values.iter().all(|value| value.abs() < f32::EPSILON as f64)
+    } else {
+        quantize(values, min, ty.max(), rounding.into(), None)
+            .iter()
+            .all(|value| *value == 0)
+    }
+}
+
+impl SineWaveAttrs {
+    /// Returns the spec of the wave, which has only the arguments that apply to every type.
+    fn spec(&self) -> WaveSpec {
+        let parse = |value: &LitInt| value.base10_parse().unwrap();
+        let mut spec = WaveSpec::new(parse(&self.frequency)).with_rate(get_rate(&self.rate).get());
+        if let Some(len) = &self.len {
+            spec = spec.with_len(len.base10_parse().unwrap());
+        }
+        if let Some(repeats) = &self.repeats {
+            spec = spec.with_repeats(repeats.base10_parse().unwrap());
+        }
+        if let Some(skip) = &self.skip {
+            spec = spec.with_skip(skip.base10_parse().unwrap());
         }
+        if let Some(modulation) = &self.modulation {
+            let modulator = parse(self.modulator.as_ref().unwrap());
+            spec = spec.with_modulation(match modulation {
+                Modulation::Am(_) => sine_macro_core::Modulation::Am {
+                    modulator,
+                    depth: self.depth.as_ref().map(parse).unwrap_or(100),
+                },
+                Modulation::Fm(_) => sine_macro_core::Modulation::Fm {
+                    modulator,
+                    deviation: parse(self.deviation.as_ref().unwrap()),
+                },
+            });
+        }
+        spec
     }
 }
 
-fn get_number_of_samples(frequency: f64, rate: f64) -> usize {
-    ((rate / frequency) as u64).try_into().unwrap()
+/// Arguments of [sine_wave!], which are either all known when the macro is expanded or include
+/// constants that are resolved by the compiler.
+enum SineWaveArgs {
+    Literal(SineWaveAttrs),
+    Constant(Box<ConstSineWaveAttrs>),
 }
 
-impl SineWaveInput {
-    fn get_attrs(&self) -> &SineWaveAttrs {
-        match self {
-            Self::Local(attrs) => attrs,
-            Self::Static(Static { attrs, .. }) => attrs,
-            Self::Const(Const { attrs, .. }) => attrs,
+impl Parse for SineWaveArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let fork = input.fork();
+        let has_constants = Punctuated::<AttrInput, Token![,]>::parse_terminated(&fork)
+            .is_ok_and(|attrs| attrs.iter().any(|attr| matches!(attr, AttrInput::Const(_))));
+        if has_constants {
+            input.parse().map(SineWaveArgs::Constant)
+        } else {
+            input.parse().map(SineWaveArgs::Literal)
         }
     }
 }
 
+impl Attrs for SineWaveArgs {
+    const NAME: &'static str = "sine_wave";
+}
+
+/// Returns the number of samples in a period, which is shared with `sine_macro_core`.
+fn get_number_of_samples(frequency: u32, rate: u32) -> usize {
+    sine_macro_core::period(frequency, rate)
+}
+
+/// Returns one period of `phases` waves, each lagging the previous one by `1 / phases` of the
+/// period.
+///
+/// Third harmonic injection and space vector (min-max) modulation add the same zero sequence
+/// component to every phase and scale the result by `2 / √3`, so that the waves peak at one.
+fn get_phase_waves(frequency: u32, rate: u32, phases: usize, shape: Shape) -> Vec<Vec<f64>> {
+    let waves: Vec<_> = (0..phases as u32)
+        .map(|k| lagging_sine_wave(frequency, rate, k, phases as u32))
+        .collect();
+    let values = waves[0].len();
+    let frames: Vec<Vec<_>> = (0..values)
+        .map(|i| {
+            let frame: Vec<_> = waves.iter().map(|wave| wave[i]).collect();
+            let offset = match shape {
+                Shape::Sine => return frame,
+                // The third harmonic is the first phase sampled three times as fast
+                Shape::ThirdHarmonic => waves[0][i * 3 % values] / 6_f64,
+                Shape::SpaceVector => {
+                    let (min, max) = frame
+                        .iter()
+                        .fold((f64::MAX, f64::MIN), |(min, max), value| {
+                            (min.min(*value), max.max(*value))
+                        });
+                    -(min + max) / 2_f64
+                }
+            };
+            let scale = 2_f64 / 3_f64.sqrt();
+            frame
+                .into_iter()
+                .map(|value| (value + offset) * scale)
+                .collect()
+        })
+        .collect();
+    (0..phases)
+        .map(|k| frames.iter().map(|frame| frame[k]).collect())
+        .collect()
+}
+
 /// Generates an array of signed integers for a sine wave.
 ///
 /// Sample rate and frequency of the wave can be controlled with `rate` and `frequency`
@@ -353,8 +1043,12 @@ impl SineWaveInput {
 /// let wave = sine_wave!(rate: 48_000, frequency: 400);
 /// ```
 ///
-/// `type` defines the data type of the array. It can be any of [`i8`], [`i16`] and [`i32`]. Defaults to
-/// [`i16`] when unspecified. The values will always span the whole range of the type sans `MIN`.
+/// `type` defines the data type of the array. It can be any of [`i8`], [`i16`], `i24`, [`i32`],
+/// [`i64`], [`i128`], [`f32`] and [`f64`], and with `pwm_top` or `pdm` also any of [`u8`], [`u16`]
+/// and [`u32`]. Defaults to [`i16`] when unspecified. By default the values of integer types span
+/// the whole range of the type sans `MIN`, but that can be changed with `full_scale`. Values of
+/// floating point types are in range `-1.0..=1.0` and they are not rounded, so `rounding`,
+/// `dither` and `full_scale` cannot be used with them.
 ///
 /// ```rust
 /// # use sine_macro::sine_wave;
@@ -362,6 +1056,47 @@ impl SineWaveInput {
 /// let wave = sine_wave!(frequency: 100, type: i8);
 /// ```
 ///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let wave = sine_wave!(frequency: 25, rate: 100, type: f32);
+/// assert_eq!(wave, [0.0, 1.0, 0.0, -1.0]);
+/// ```
+///
+/// The values are calculated with [`f64`], so [`i64`] and [`i128`] values are as precise as the
+/// 53-bit mantissa of [`f64`] allows, which is enough for phase accumulators and fixed point math
+/// of the same width but not to use all of their bits.
+///
+/// With the `fixed` feature `type` can also be a signed fixed point type of the `fixed` crate, such
+/// as `I1F15` or `I8F8`, and with the `half` feature it can be `f16` of the `half` crate. The
+/// values are emitted with `from_bits`, so they can be used in const items. Fixed point types are
+/// scaled so that the peaks are at one, or as close to it as the type can hold, e.g. `I1F15` gets
+/// the same values as [`i16`]. `f16` works like the other floating point types.
+///
+/// `type` can also be a path to any other type, such as a newtype wrapper, if `storage` is given.
+/// See [custom types][crate::sine_wave!#custom-types] for more.
+///
+/// ```rust
+/// # #[cfg(feature = "fixed")] {
+/// # use sine_macro::sine_wave;
+/// use fixed::types::I8F8;
+/// const WAVE: [I8F8; 4] = sine_wave!(frequency: 100, rate: 400, type: I8F8);
+/// assert_eq!(WAVE, [I8F8::ZERO, I8F8::ONE, I8F8::ZERO, -I8F8::ONE]);
+/// # }
+/// ```
+///
+/// `i24` values span the range of 24-bit integers and they are stored in [`i32`]. `justify` sets
+/// how they are stored: with `right` the values are in the low 24 bits, as with no `justify`, and
+/// with `left` they are in the high 24 bits with zeros in the low byte, as expected by many I2S
+/// peripherals. `justify` can be used only with `i24`.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let right = sine_wave!(frequency: 100, rate: 400, type: i24);
+/// assert_eq!(right, [0, 8_388_607, 0, -8_388_607]);
+/// let left = sine_wave!(frequency: 100, rate: 400, type: i24, justify: left);
+/// assert_eq!(left, [0, 8_388_607 << 8, 0, -8_388_607 << 8]);
+/// ```
+///
 /// `len` specifies how many samples the array must contain. This may cut the wave short on any
 /// period but it can be also used for generating waves of specific duration. E.g. one second long
 /// wave can be generated by setting (sampling) `rate` and `len` to the same value. However the
@@ -404,6 +1139,103 @@ impl SineWaveInput {
 /// let wave = sine_wave!(frequency: 400, skip: 100);
 /// ```
 ///
+/// `modulation` selects either amplitude (`am`) or frequency (`fm`) modulation of the wave.
+/// `modulator` specifies the frequency of the modulating wave and it is required with
+/// `modulation`. Amplitude modulation accepts `depth` and frequency modulation requires
+/// `deviation`. See [Modulation][crate::sine_wave!#modulation] for more information.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // Sine wave of 440 Hz with 5 Hz tremolo
+/// let wave = sine_wave!(frequency: 440, rate: 48_000, modulation: am, modulator: 5);
+/// ```
+///
+/// `rounding` selects how the values are rounded to integers after scaling them to the range of
+/// the type. It can be any of `truncate`, `nearest`, `floor` and `ceil`. `nearest` rounds halfway
+/// values away from zero. Defaults to `truncate`, which rounds towards zero and thus biases the
+/// values towards zero. That is most visible with small types like [`i8`].
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let truncated = sine_wave!(frequency: 10, rate: 100, type: i8);
+/// assert_eq!(truncated, [0, 74, 120, 120, 74, 0, -74, -120, -120, -74]);
+/// let rounded = sine_wave!(frequency: 10, rate: 100, type: i8, rounding: nearest);
+/// assert_eq!(rounded, [0, 75, 121, 121, 75, 0, -75, -121, -121, -75]);
+/// ```
+///
+/// `dither` adds triangular probability density function (TPDF) dither of up to one least
+/// significant bit to the values before rounding. The value of `dither` is the seed for the
+/// pseudorandom number generator used, so the same seed always produces the same array. The
+/// dither is generated for one period, which is then repeated like the wave itself.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // Dithered sine wave of 440 Hz with i8 data type
+/// let wave = sine_wave!(frequency: 440, type: i8, rounding: nearest, dither: 1234);
+/// ```
+///
+/// `full_scale` selects how the wave is scaled to the range of the type. It can be either
+/// `symmetric` or `asymmetric`, and defaults to `symmetric`. With `symmetric` every value `x` of
+/// the wave is mapped to `x * MAX`, so the values span `-MAX..=MAX` of the type. With
+/// `asymmetric` the positive values are mapped the same way but negative values are mapped to
+/// `x * -MIN`, i.e. `x * (MAX + 1)`, so that the negative peak is `MIN`. Rounding is applied
+/// after scaling and the results are clamped to the range.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let symmetric = sine_wave!(frequency: 25, rate: 100, type: i8);
+/// assert_eq!(symmetric, [0, 127, 0, -127]);
+/// let asymmetric = sine_wave!(frequency: 25, rate: 100, type: i8, full_scale: asymmetric);
+/// assert_eq!(asymmetric, [0, 127, 0, -128]);
+/// ```
+///
+/// `quadrature` generates a cosine wave in addition to the sine wave. Both of them have the same
+/// length and scaling, and `len`, `repeats`, `skip` and the other arguments apply to both. Unlike
+/// shifting the sine wave with `skip`, the cosine wave is exact even when the length of the period
+/// is not divisible by four. With `tables` the result is a tuple of sine and cosine arrays and
+/// with `pairs` it is an array of `(sine, cosine)` tuples. With `dither` the cosine wave is
+/// dithered with the first output of the pseudorandom number generator seeded with `dither` as its
+/// seed, so the dither of the two waves is independent. `quadrature` cannot be used with
+/// `modulation`.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let (sine, cosine) = sine_wave!(frequency: 25, rate: 100, type: i8, quadrature: tables);
+/// assert_eq!(sine, [0, 127, 0, -127]);
+/// assert_eq!(cosine, [127, 0, -127, 0]);
+/// let pairs = sine_wave!(frequency: 25, rate: 100, type: i8, quadrature: pairs);
+/// assert_eq!(pairs, [(0, 127), (127, 0), (0, -127), (-127, 0)]);
+/// ```
+///
+/// `phases` generates the given number of waves, each lagging the previous one by `1 / phases` of
+/// the period, e.g. by 120° with `phases: 3`. See
+/// [Multiple phases][crate::sine_wave!#multiple-phases] for more information.
+///
+/// `pwm_top` maps the wave to PWM compare values for a timer instead of signed values. See
+/// [PWM][crate::sine_wave!#pwm] for more information.
+///
+/// `pdm` generates a pulse-density modulated bitstream of the wave packed into words. See
+/// [Pulse-density modulation][crate::sine_wave!#pulse-density-modulation] for more information.
+///
+/// `bytes` turns the samples into raw bytes of the type in little endian, `le`, or big endian,
+/// `be`, byte order, e.g. for DMA transfers or file formats. The result is an array of [`u8`] whose
+/// length is the number of samples times the size of the type. `i24` samples are packed into three
+/// bytes, unless `justify` is given, in which case they take four bytes like [`i32`] values do.
+/// Pairs of `quadrature: pairs` and frames of `layout: frames` are interleaved into one array,
+/// while separate tables become separate arrays.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let samples = sine_wave!(frequency: 100, rate: 800, type: i16);
+/// let bytes = sine_wave!(frequency: 100, rate: 800, type: i16, bytes: le);
+/// assert_eq!(bytes.len(), 16);
+/// assert_eq!(bytes[2..4], samples[1].to_le_bytes());
+///
+/// // Interleaved stereo samples in big endian byte order
+/// let bytes = sine_wave!(frequency: 100, rate: 800, type: i16, quadrature: pairs, bytes: be);
+/// assert_eq!(bytes[..4], [0x00, 0x00, 0x7f, 0xff]);
+/// ```
+///
 /// # Use with static and const
 /// Since `const` and `static` items must have their types defined and a macro cannot override
 /// that, this provides a syntax similar to
@@ -438,6 +1270,208 @@ impl SineWaveInput {
 /// }
 /// ```
 ///
+/// # Constants
+/// Integer arguments can be given as arithmetic expressions of integer literals using `+`, `-`,
+/// `*`, `/`, `%` and parentheses, which are evaluated like the same expressions in Rust. This
+/// works with the other macros of this crate too.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let wave = sine_wave!(frequency: 440, rate: 48 * 1_000);
+/// ```
+///
+/// `frequency` and `rate` can also be any constant expressions of type [`u32`], such as paths to
+/// constants. Then the array is computed by the compiler in const context instead, which means
+/// that only `len`, `repeats`, `skip`, `rounding`, `full_scale` and `type` can be used with them,
+/// and `type` must be a signed integer or floating point type. The generated code calls the const
+/// functions of the `sine_macro_const` crate, which must be a dependency of the crate that uses
+/// this, and the values are the same as the ones generated for literal arguments. Invalid values,
+/// such as `frequency` higher than `rate`, fail the const evaluation.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// const SAMPLE_RATE: u32 = 48_000;
+///
+/// sine_wave! {
+///     const BEEP = sine_wave(frequency: 1_000, rate: SAMPLE_RATE, repeats: 2);
+/// }
+/// assert_eq!(BEEP, sine_wave!(frequency: 1_000, rate: 48_000, repeats: 2));
+///
+/// let wave = sine_wave!(frequency: 1_000, rate: SAMPLE_RATE / 2, type: f32);
+/// assert_eq!(wave.len(), 24);
+/// ```
+///
+/// The `sine_macro_const` crate provides the default [`i16`] waves as const functions, which
+/// compute exactly the same values as this macro with literal arguments.
+///
+/// # Modulation
+/// A modulated wave does not have a period of its own, so instead the array contains the
+/// shortest loop that spans whole periods of both `frequency` and `modulator`. That is
+/// `rate / gcd(rate, frequency, modulator)` samples, which means that no
+/// [rounding][crate::sine_wave!#rounding] is applied to the frequencies but the arrays can get
+/// long. `len`, `repeats` and `skip` work on the loop like they otherwise work on a period.
+///
+/// Amplitude modulation takes `depth` in percent, which defaults to 100. The amplitude varies
+/// between the full range of the type and `1 - depth / 100` of it following the modulator.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // Tremolo of 5 Hz with half depth on a 440 Hz tone
+/// let wave = sine_wave!(frequency: 440, rate: 48_000, modulation: am, modulator: 5, depth: 50);
+/// assert_eq!(wave.len(), 9_600);
+/// ```
+///
+/// Frequency modulation takes `deviation`, which is the peak deviation from `frequency` in Hz. It
+/// must be less than `frequency`.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // Siren warbling between 600 Hz and 1,000 Hz four times a second
+/// let wave = sine_wave!(frequency: 800, rate: 16_000, modulation: fm, modulator: 4, deviation: 200);
+/// assert_eq!(wave.len(), 4_000);
+/// ```
+///
+/// # Multiple phases
+/// `phases` makes the macro generate multiple waves that are shifted in phase, as needed for
+/// example by three-phase inverters and motor control. Phase `k` is `sin(θ - k · 2π / phases)`
+/// for every sample. The waves have the same length and scaling, and all other arguments apply to
+/// every one of them. `phases` cannot be used with `modulation` or `quadrature`.
+///
+/// `layout` selects how the waves are returned. With `tables`, the default, the result is a tuple
+/// of arrays, one for each phase. With `frames` it is an array of frames, each containing a value
+/// for every phase, e.g. `[[i16; 3]; N]` for three phases.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let (a, b, c) = sine_wave!(frequency: 25, rate: 150, type: i8, phases: 3);
+/// assert_eq!(a, [0, 109, 109, 0, -109, -109]);
+/// assert_eq!(b, [-109, -109, 0, 109, 109, 0]);
+/// assert_eq!(c, [109, 0, -109, -109, 0, 109]);
+///
+/// let frames = sine_wave!(frequency: 25, rate: 150, type: i8, phases: 3, layout: frames);
+/// assert_eq!(frames[1], [109, -109, 0]);
+/// ```
+///
+/// With three phases `shape` can also be used for adding a zero sequence component to every
+/// phase, which does not change the voltages between the phases but lowers the peak of the waves
+/// by `√3 / 2`. This allows driving the phases with higher amplitude, so the waves are scaled to
+/// peak at full scale again. `shape` can be any of
+///  - `sine`, the default, for pure sine waves,
+///  - `third_harmonic` for adding a sixth of the third harmonic, and
+///  - `space_vector` for adding the negated average of the highest and lowest phase, which is
+///    equivalent to space vector modulation.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // Space vector modulation for a timer counting from 0 to 1,999 as interleaved frames
+/// let frames = sine_wave!(
+///     frequency: 50,
+///     rate: 10_000,
+///     phases: 3,
+///     shape: space_vector,
+///     layout: frames,
+///     pwm_top: 1_999,
+///     type: u16,
+///     rounding: nearest,
+/// );
+/// ```
+///
+/// # PWM
+/// `pwm_top` maps the wave to duty cycle compare values in range `0..=pwm_top` for a timer whose
+/// counter wraps or turns around at `pwm_top`, so that the negative peak is zero, the positive peak
+/// is `pwm_top` and zero crossings are at the middle of the range. `pwm_top` must fit in the type,
+/// and unsigned types can be used only with it. `rounding` and `dither` apply to the compare
+/// values, but `full_scale` and `modulation` cannot be used with `pwm_top`.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // Compare values for a timer counting from 0 to 999
+/// let compare = sine_wave!(frequency: 50, rate: 20_000, type: u16, pwm_top: 999, rounding: nearest);
+/// assert_eq!(compare[0], 500);
+/// assert_eq!(compare[100], 999);
+/// assert_eq!(compare[300], 0);
+/// ```
+///
+/// `dead_time` clamps the compare values to range `dead_time..=pwm_top - dead_time`, so that the
+/// output is never kept on or off for the whole period and there is always room for the dead time
+/// inserted by the timer between the high and low side switches of a half bridge. It is given in
+/// timer ticks and must be less than half of `pwm_top`.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// let compare = sine_wave!(frequency: 50, rate: 20_000, type: u16, pwm_top: 999, dead_time: 20);
+/// assert_eq!(compare.iter().max(), Some(&979));
+/// assert_eq!(compare.iter().min(), Some(&20));
+/// ```
+///
+/// # Pulse-density modulation
+/// `pdm` runs a first order sigma-delta modulator over the wave and packs the resulting 1-bit
+/// stream into words of the type, which must be any of [`u8`], [`u16`] and [`u32`]. This is
+/// suitable for driving a speaker or a filter directly from a GPIO pin or the data output of an
+/// SPI or I2S peripheral. The value of `pdm` is the oversampling ratio, i.e. the number of bits for
+/// every sample of the wave, so the bitstream must be clocked out at `rate` times `pdm` bits per
+/// second.
+///
+/// `bit_order` sets whether the first bit of each word is the most significant bit, `msb`, or the
+/// least significant bit, `lsb`. Defaults to `msb`, which is how SPI peripherals usually shift out
+/// the data.
+///
+/// The output contains as many periods of the wave as needed for the bitstream to fill whole words,
+/// so that it can be looped. `len`, `repeats` and `skip` count words instead of samples.
+/// `modulation`, `quadrature`, `phases`, `pwm_top`, `rounding`, `dither` and `full_scale` cannot be
+/// used with `pdm`.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// // 1 kHz tone for 64 times oversampled 8 kHz audio, clocked out at 512 kHz
+/// let bitstream = sine_wave!(frequency: 1_000, rate: 8_000, pdm: 64, type: u32);
+/// assert_eq!(bitstream.len(), 16);
+/// let ones: u32 = bitstream.iter().map(|word| word.count_ones()).sum();
+/// assert_eq!(ones, 256);
+///
+/// let msb = sine_wave!(frequency: 1, rate: 4, pdm: 2, type: u8);
+/// let lsb = sine_wave!(frequency: 1, rate: 4, pdm: 2, type: u8, bit_order: lsb);
+/// assert_eq!(msb, [0b1011_1000]);
+/// assert_eq!(lsb, [0b0001_1101]);
+/// ```
+///
+/// # Custom types
+/// When `type` is a path to a type other than the ones listed above, `storage` must define which
+/// of them the values are calculated as. Each value is then wrapped with `wrap`, which is a path
+/// to a tuple struct, a tuple variant or a function that takes the value of `storage` and returns
+/// the type. The function must be a `const fn` when the array is used in const context, e.g. with
+/// `const` and `static` items, but any function works otherwise. Defaults to the path in `type`,
+/// which suits tuple structs without generic parameters.
+/// All other arguments work as they would with `storage` as `type`, except that `justify` cannot be
+/// used. `storage` and `wrap` cannot be used with the other types.
+///
+/// ```rust
+/// # use sine_macro::sine_wave;
+/// #[derive(Debug, PartialEq)]
+/// struct Sample(i16);
+///
+/// let wave = sine_wave!(frequency: 100, rate: 400, type: Sample, storage: i16);
+/// assert_eq!(wave, [Sample(0), Sample(32_767), Sample(0), Sample(-32_767)]);
+///
+/// struct Volts(f32);
+///
+/// impl Volts {
+///     const fn from_unit(value: f32) -> Self {
+///         Self(value * 3.3)
+///     }
+/// }
+///
+/// const VOLTS: [Volts; 4] =
+///     sine_wave!(frequency: 100, rate: 400, type: Volts, storage: f32, wrap: Volts::from_unit);
+///
+/// fn from_millivolts(value: i16) -> Volts {
+///     Volts(value as f32 / 1_000.0)
+/// }
+///
+/// let volts =
+///     sine_wave!(frequency: 100, rate: 400, type: Volts, storage: i16, wrap: from_millivolts);
+/// ```
+///
 /// # Rounding
 /// Rounding of the length of the array can affect the sine wave slightly. This always rounds
 /// before generating the wave so the waves will always start from zero and end so that the next
@@ -452,92 +1486,602 @@ impl SineWaveInput {
 /// ```
 #[proc_macro]
 pub fn sine_wave(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = parse_macro_input!(tokens as SineWaveInput);
-    let attrs = input.get_attrs();
+    let input = parse_macro_input!(tokens as Input<SineWaveArgs>);
+    let attrs = match input.get_attrs() {
+        SineWaveArgs::Literal(attrs) => attrs,
+        SineWaveArgs::Constant(attrs) => {
+            let float = attrs.is_float();
+            let (ty, tokens) = attrs.expand();
+            return input.expand(ty, tokens, float);
+        }
+    };
     let ty = attrs.ty.clone();
+    let float = ty.is_float();
     let frequency: NonZeroU32 = attrs.frequency.clone().base10_parse().unwrap();
-    let rate: NonZeroU32 = attrs
-        .rate
-        .clone()
-        .map(|input| input.base10_parse().unwrap())
-        .unwrap_or_else(|| NonZero::new(DEFAULT_RATE).unwrap());
-    let values = get_number_of_samples(frequency.get() as f64, rate.get() as f64);
-    let count;
-    let sine_wave_tokens = {
-        let multiplier = PI * 2_f64 / values as f64;
-        let samples: Vec<_> = (0..values)
-            .map(|i| (i as f64 * multiplier))
-            .map(f64::sin)
-            .map(|value| value * ty.max() as f64)
-            .map(|value| value as i32)
-            .collect();
+    let rate = get_rate(&attrs.rate);
+    let spec = attrs.spec();
+    // The arguments have been checked already
+    let wave = spec.wave().unwrap();
+    let (sine_wave_ty, sine_wave_tokens) = {
+        let rounding = attrs.rounding.unwrap_or(Rounding::Truncate);
+        let min = ty.min(attrs.full_scale.unwrap_or(FullScale::Symmetric));
+        let pwm_top: Option<i128> = attrs
+            .pwm_top
+            .as_ref()
+            .map(|top| top.base10_parse().unwrap());
+        let dead_time: i128 = attrs
+            .dead_time
+            .as_ref()
+            .map(|dead_time| dead_time.base10_parse().unwrap())
+            .unwrap_or(0);
         // Just a little sanity check
-        if !samples.iter().any(|x| *x != 0) {
+        if pwm_top.is_none() && attrs.pdm.is_none() && is_silent(&ty, &wave, min, rounding) {
             return {
                 Error::new_spanned(
                     &attrs.frequency,
-                    format_args!(
-                        "could not generate sine wave for `rate` of {} Hz and `frequency` of {} Hz",
-                        rate, frequency
-                    ),
+                    sine_macro_core::Error::Silent {
+                        rate: rate.get(),
+                        frequency: frequency.get(),
+                    },
                 )
                 .into_compile_error()
                 .into()
             };
         }
-        count = attrs
-            .len
-            .clone()
-            .map(|input| input.base10_parse().unwrap())
-            .unwrap_or_else(|| {
-                samples.len()
-                    * attrs
-                        .repeats
-                        .clone()
-                        .map(|input| input.base10_parse().unwrap())
-                        .unwrap_or(1)
-            });
-        let skip = attrs
-            .skip
-            .clone()
-            .map(|input| input.base10_parse().unwrap())
-            .unwrap_or(0);
-        let tokens = TokenStream::from_iter(
-            samples
+        let dither = attrs
+            .dither
+            .as_ref()
+            .map(|seed| seed.base10_parse().unwrap());
+        let samples: Vec<Vec<_>> = if let Some(oversampling) = &attrs.pdm {
+            let words = get_pdm_words(
+                frequency.get(),
+                rate.get(),
+                oversampling.base10_parse().unwrap(),
+                ty.bits(),
+                attrs.bit_order.unwrap_or(BitOrder::Msb),
+            );
+            vec![
+                words
+                    .into_iter()
+                    .map(|word| Sample::Int(word.into()))
+                    .collect(),
+            ]
+        } else {
+            let waves = match (&attrs.phases, attrs.quadrature) {
+                (Some(phases), _) => get_phase_waves(
+                    frequency.get(),
+                    rate.get(),
+                    phases.base10_parse().unwrap(),
+                    attrs.shape.unwrap_or(Shape::Sine),
+                ),
+                (None, Some(_)) => vec![wave, cosine_wave(frequency.get(), rate.get())],
+                (None, None) => vec![wave],
+            };
+            waves
                 .iter()
-                .cycle()
-                .skip(skip)
-                .take(count)
-                .map(|value| TokenTree::Literal(ty.literal(*value)))
-                .interleave(repeat_n(
-                    TokenTree::from(Punct::new(',', Spacing::Alone)),
-                    count - 1,
-                )),
-        );
-        TokenStream::from(TokenTree::from(Group::new(Delimiter::Bracket, tokens)))
-    };
-    match input {
-        SineWaveInput::Local(_) => sine_wave_tokens.into(),
-        SineWaveInput::Static(item) => {
-            assert_eq!(item.name, "sine_wave");
-            let vis = item.vis;
-            let mutability = item.mutability;
-            let ident = item.ident;
-            let ty = ty.ident();
-            quote! {
-                #vis static #mutability #ident: [#ty; #count] = #sine_wave_tokens;
+                .enumerate()
+                .map(|(i, wave)| {
+                    // The cosine gets its own seed so that its dither does not follow the sine's
+                    let dither = match attrs.quadrature {
+                        Some(_) if i == 1 => dither.map(|seed| SplitMix64::new(seed).next_u64()),
+                        _ => dither,
+                    };
+                    match pwm_top {
+                        Some(top) => to_pwm_samples(wave, top, dead_time, rounding, dither),
+                        None => to_samples(&ty, wave, min, rounding, dither),
+                    }
+                })
+                .collect()
+        };
+        // Justified 24-bit samples take the whole `i32`
+        let (ty, samples) = match (attrs.justify, &ty) {
+            (Some(justify), Some(Type::I24(ident))) => {
+                let shift = match justify {
+                    Justify::Left => i32::BITS - 24,
+                    Justify::Right => 0,
+                };
+                let samples = samples
+                    .into_iter()
+                    .map(|table| {
+                        table
+                            .into_iter()
+                            .map(|sample| match sample {
+                                Sample::Int(value) => Sample::Int(value << shift),
+                                sample => sample,
+                            })
+                            .collect()
+                    })
+                    .collect();
+                let ty = Type::I32(syn::Ident::new("i32", ident.span()));
+                (Some(ty), samples)
             }
-            .into()
-        }
-        SineWaveInput::Const(item) => {
-            assert_eq!(item.name, "sine_wave");
-            let vis = item.vis;
-            let ident = item.ident;
-            let ty = ty.ident();
-            quote! {
-                #vis const #ident: [#ty; #count] = #sine_wave_tokens;
+            _ => (ty, samples),
+        };
+        let tables: Vec<Vec<_>> = samples
+            .into_iter()
+            .map(|samples| spec.arrange(&samples))
+            .collect();
+        let count = tables[0].len();
+        let ident = ty.path();
+        let width = tables.len();
+        let interleaved = matches!(attrs.quadrature, Some(Quadrature::Pairs))
+            || matches!(attrs.layout, Some(PhaseLayout::Frames));
+        if let Some(order) = attrs.bytes {
+            let tables: Vec<Vec<_>> = if interleaved {
+                let frames = (0..count).flat_map(|i| tables.iter().map(move |table| table[i]));
+                vec![frames.collect()]
+            } else {
+                tables
+            };
+            let tables: Vec<_> = tables
+                .into_iter()
+                .map(|table| {
+                    let bytes: Vec<_> = table
+                        .into_iter()
+                        .flat_map(|sample| sample.bytes(&ty, order))
+                        .map(proc_macro2::Literal::u8_suffixed)
+                        .collect();
+                    let len = bytes.len();
+                    (quote!([u8; #len]), array(bytes, len))
+                })
+                .collect();
+            if tables.len() > 1 {
+                let (types, tables): (Vec<_>, Vec<_>) = tables.into_iter().unzip();
+                (quote!((#(#types),*)), quote!((#(#tables),*)))
+            } else {
+                tables.into_iter().next().unwrap()
             }
-            .into()
+        } else if let Some(Quadrature::Pairs) = attrs.quadrature {
+            let pairs = (0..count).map(|i| {
+                let (sine, cosine) = (tables[0][i].literal(&ty), tables[1][i].literal(&ty));
+                Group::new(Delimiter::Parenthesis, quote!(#sine, #cosine))
+            });
+            (quote!([(#ident, #ident); #count]), array(pairs, count))
+        } else if let Some(PhaseLayout::Frames) = attrs.layout {
+            let frames = (0..count).map(|i| {
+                let values = tables.iter().map(|table| table[i].literal(&ty));
+                Group::new(Delimiter::Bracket, quote!(#(#values),*))
+            });
+            (quote!([[#ident; #width]; #count]), array(frames, count))
+        } else if width > 1 {
+            let tables = tables.into_iter().map(|table| {
+                let values = table.into_iter().map(|sample| sample.literal(&ty));
+                array(values, count)
+            });
+            let types = repeat_n(quote!([#ident; #count]), width);
+            (quote!((#(#types),*)), quote!((#(#tables),*)))
+        } else {
+            let table = tables.into_iter().next().unwrap();
+            let values = table.into_iter().map(|sample| sample.literal(&ty));
+            (quote!([#ident; #count]), array(values, count))
         }
-    }
+    };
+    input.expand(sine_wave_ty, sine_wave_tokens, float)
+}
+
+/// Generates an array of signed integer noise.
+///
+/// The noise is generated with a pseudorandom number generator that is seeded with `seed`, so the
+/// same arguments always produce the same array. `seed` and `len` are required, and neither of
+/// them can be zero. `type` works the same way as with [sine_wave!] and the values span the whole
+/// range of the type sans `MIN`. The same syntax for [const and static
+/// items][crate::sine_wave!#use-with-static-and-const] is also supported with `noise` as the
+/// identifier.
+///
+/// `kind` selects the kind of noise and it can be any of `white`, `pink` and `lfsr`. Defaults to
+/// `white` when unspecified.
+///  - `white` is uniformly distributed white noise.
+///  - `pink` is white noise filtered to pink noise with Paul Kellett's refined method. It is
+///    normalized so that the largest value of the array is at full scale.
+///  - `lfsr` is the output of a 64-bit maximal length linear-feedback shift register with the
+///    seed as its initial state. The values are either `MAX` or `-MAX` of the type.
+///
+/// # Examples
+/// ```rust
+/// # use sine_macro::noise;
+/// // 1,000 samples of white noise
+/// let white = noise!(seed: 1234, len: 1_000);
+/// assert_eq!(white, noise!(seed: 1234, len: 1_000, kind: white));
+///
+/// // Pink noise as i8 array
+/// let pink = noise!(seed: 42, len: 256, kind: pink, type: i8);
+///
+/// // Noise from LFSR
+/// let lfsr = noise!(seed: 1, len: 64, kind: lfsr, type: i8);
+/// assert!(lfsr.iter().all(|value| value.abs() == i8::MAX));
+/// ```
+///
+/// And as a const item:
+/// ```rust
+/// # use sine_macro::noise;
+/// noise! {
+///     pub const DITHER = noise(seed: 0xC0FFEE, len: 64, type: i8);
+/// }
+/// ```
+#[proc_macro]
+pub fn noise(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as Input<NoiseAttrs>);
+    let attrs = input.get_attrs();
+    let ty = attrs.ty.clone();
+    let seed: NonZeroU64 = attrs.seed.base10_parse().unwrap();
+    let count: usize = attrs.len.base10_parse().unwrap();
+    let noise = get_noise(attrs.kind, seed, count);
+    let min = ty.min(FullScale::Symmetric);
+    let samples = to_literals(&ty, &noise, min, Rounding::Truncate, None);
+    let tokens = array(samples, count);
+    let float = ty.is_float();
+    let ty = ty.path();
+    input.expand(quote!([#ty; #count]), tokens, float)
+}
+
+/// Generates an array of signed integers for a window function.
+///
+/// `kind` selects the window function and `len` the number of values in the array. Both of them
+/// are required. The values are scaled so that one is `MAX` of the type, like the peaks of
+/// [sine_wave!], and `type` works the same way. The same syntax for [const and static
+/// items][crate::sine_wave!#use-with-static-and-const] is also supported with `window` as the
+/// identifier.
+///
+/// `kind` can be any of
+///  - `rectangular`, which is all ones.
+///  - `hann`, i.e. `0.5 - 0.5 cos(2πn/N)`.
+///  - `hamming`, i.e. `0.54 - 0.46 cos(2πn/N)`.
+///  - `blackman`, the classic three term Blackman window.
+///  - `blackman_harris`, the four term Blackman-Harris window.
+///  - `flat_top`, the five term flat top window. It has small negative values and its peak is
+///    slightly above one, so it saturates at `MAX`.
+///  - `kaiser`, which requires `beta` that can be given either as an integer or as a floating
+///    point number.
+///
+/// `symmetry` can be either `symmetric` or `periodic`, and defaults to `symmetric`. Symmetric
+/// windows start and end at the same value (`N = len - 1` above) and they are suited for filter
+/// design. Periodic windows are the first `len` values of a symmetric window of `len + 1` values
+/// (`N = len`) and they are suited for spectral analysis with FFT.
+///
+/// # Examples
+/// ```rust
+/// # use sine_macro::window;
+/// let hann = window!(kind: hann, len: 5);
+/// assert_eq!(hann, [0, 16383, 32767, 16383, 0]);
+///
+/// let hann = window!(kind: hann, len: 4, symmetry: periodic);
+/// assert_eq!(hann, [0, 16383, 32767, 16383]);
+///
+/// let kaiser = window!(kind: kaiser, beta: 8.6, len: 64, type: i32);
+/// ```
+///
+/// And as a static item:
+/// ```rust
+/// # use sine_macro::window;
+/// window! {
+///     pub static FFT_WINDOW = window(kind: blackman_harris, len: 256, symmetry: periodic);
+/// }
+/// ```
+#[proc_macro]
+pub fn window(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as Input<WindowAttrs>);
+    let attrs = input.get_attrs();
+    let ty = attrs.ty.clone();
+    let count: usize = attrs.len.base10_parse().unwrap();
+    let window = get_window(
+        attrs.kind,
+        attrs.beta.as_ref().map(|beta| beta.value),
+        attrs.symmetry.unwrap_or(Symmetry::Symmetric),
+        count,
+    );
+    let min = ty.min(FullScale::Symmetric);
+    let values = to_literals(&ty, &window, min, Rounding::Truncate, None);
+    let tokens = array(values, count);
+    let float = ty.is_float();
+    let ty = ty.path();
+    input.expand(quote!([#ty; #count]), tokens, float)
+}
+
+/// Generates an array of FIR filter coefficients.
+///
+/// The coefficients are designed with the windowed-sinc method and scaled for unity gain at the
+/// center of the pass band. `kind` selects the kind of the filter and `taps` the number of
+/// coefficients, and both of them are required. `kind` can be any of `lowpass`, `highpass`,
+/// `bandpass` and `bandstop`.
+///
+/// `rate` specifies the sampling rate and defaults to 44,100 Hz like with [sine_wave!]. Low-pass
+/// and high-pass filters require `cutoff` and band-pass and band-stop filters require `low` and
+/// `high` cutoff frequencies. All of them are in Hz and must be less than half of `rate`.
+/// High-pass and band-stop filters must have an odd number of `taps`.
+///
+/// `window` selects the window function, which can be any of the windows supported by
+/// [window!], and defaults to `hamming`. `kaiser` requires `beta`.
+///
+/// `transition` specifies the required width of the transition band in Hz. If it is given, the
+/// macro refuses to generate filters that have too few taps for it. The transition band of a
+/// windowed-sinc filter is about as wide as the main lobe of the window, so `taps` must be at
+/// least `width * rate / transition`, where `width` is 2 for rectangular, 4 for Hann and Hamming,
+/// 6 for Blackman, 8 for Blackman-Harris and 10 for flat top windows, and `2√(1 + (β/π)²)` for
+/// Kaiser windows.
+///
+/// `type` can be any of the types supported by [sine_wave!]. Integer types are treated as fixed
+/// point numbers with `fraction` fraction bits, which defaults to all bits but the sign, e.g.
+/// [`i16`] as Q15, and the coefficients are rounded to the nearest value. It is an error if a
+/// coefficient does not fit in the type, which happens with Q15 if the coefficient is one, so a
+/// smaller `fraction` leaves headroom for such filters. Fixed point types of the `fixed` crate use
+/// their own fraction bits and floating point types are emitted as is.
+///
+/// The same syntax for [const and static items][crate::sine_wave!#use-with-static-and-const] is
+/// also supported with `fir` as the identifier.
+///
+/// # Examples
+/// ```rust
+/// # use sine_macro::fir;
+/// // Low-pass filter at 1 kHz for 16 kHz sampling rate as Q15 coefficients
+/// let lowpass = fir!(kind: lowpass, rate: 16_000, cutoff: 1_000, taps: 31);
+///
+/// // Band-pass filter for telephone band with Blackman window as f32 coefficients
+/// let bandpass = fir!(
+///     kind: bandpass,
+///     rate: 8_000,
+///     low: 300,
+///     high: 3_400,
+///     taps: 63,
+///     window: blackman,
+///     type: f32,
+/// );
+/// ```
+///
+/// And as a const item:
+/// ```rust
+/// # use sine_macro::fir;
+/// fir! {
+///     pub const HIGHPASS = fir(kind: highpass, rate: 48_000, cutoff: 100, taps: 255, transition: 1_000);
+/// }
+/// ```
+///
+/// Too few taps for the requested transition band is an error:
+/// ```compile_fail
+/// # use sine_macro::fir;
+/// let lowpass = fir!(kind: lowpass, rate: 48_000, cutoff: 1_000, taps: 31, transition: 100);
+/// ```
+#[proc_macro]
+pub fn fir(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as Input<FirAttrs>);
+    let attrs = input.get_attrs();
+    let ty = attrs.ty.clone();
+    let count: usize = attrs.taps.base10_parse().unwrap();
+    let coefficients = fir::get_coefficients(attrs);
+    let span = attrs
+        .fraction
+        .as_ref()
+        .map(|fraction| fraction.span())
+        .unwrap_or_else(|| attrs.taps.span());
+    let values = match to_coefficient_literals(&ty, &coefficients, &attrs.fraction, 1, span) {
+        Ok(values) => values,
+        Err(error) => return error.into_compile_error().into(),
+    };
+    let tokens = array(values, count);
+    let float = ty.is_float();
+    let ty = ty.path();
+    input.expand(quote!([#ty; #count]), tokens, float)
+}
+
+/// Generates biquad filter coefficients.
+///
+/// The coefficients are calculated with the formulas of the [Audio EQ Cookbook][cookbook] and
+/// returned as an array of `[b0, b1, b2, a1, a2]`, normalized so that `a0` is one. They are meant
+/// for the direct form `y[n] = b0 x[n] + b1 x[n-1] + b2 x[n-2] - a1 y[n-1] - a2 y[n-2]`.
+///
+/// `kind` selects the kind of the filter and can be any of `lowpass`, `highpass`, `peaking`,
+/// `lowshelf`, `highshelf` and `notch`. `frequency` is the cutoff or center frequency in Hz and
+/// must be less than half of `rate`. Both of them are required. `rate` specifies the sampling rate
+/// and defaults to 44,100 Hz like with [sine_wave!].
+///
+/// `q` is the quality factor, which can be given either as an integer or as a floating point
+/// number, and defaults to `1/√2`. Peaking and shelving filters also require `gain` in decibels,
+/// which may be negative.
+///
+/// `type` can be any of the types supported by [sine_wave!]. Integer types are treated as fixed
+/// point numbers with `fraction` fraction bits, which defaults to two bits less than the size of
+/// the type, e.g. Q2.14 for [`i16`], since the coefficients can be up to two in magnitude. The
+/// coefficients are rounded to the nearest value and it is an error if they do not fit in the
+/// type. Fixed point types of the `fixed` crate use their own fraction bits, so `fraction` cannot
+/// be used with them. Floating point types are emitted as is.
+///
+/// The same syntax for [const and static items][crate::sine_wave!#use-with-static-and-const] is
+/// also supported with `biquad` as the identifier.
+///
+/// [cookbook]: https://www.w3.org/TR/audio-eq-cookbook/
+///
+/// # Examples
+/// ```rust
+/// # use sine_macro::biquad;
+/// // Low-pass filter at 1 kHz for 48 kHz sampling rate as Q2.14 coefficients
+/// let [b0, b1, b2, a1, a2] = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000);
+///
+/// // Peaking filter cutting 6 dB at 3 kHz as f32 coefficients
+/// let peaking = biquad!(kind: peaking, rate: 48_000, frequency: 3_000, q: 2.5, gain: -6, type: f32);
+///
+/// // High-shelf filter as Q1.30 coefficients
+/// let shelf = biquad!(kind: highshelf, frequency: 8_000, gain: 3.5, type: i32, fraction: 30);
+/// ```
+///
+/// And as a static item:
+/// ```rust
+/// # use sine_macro::biquad;
+/// biquad! {
+///     static NOTCH = biquad(kind: notch, rate: 8_000, frequency: 50, q: 10);
+/// }
+/// ```
+#[proc_macro]
+pub fn biquad(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as Input<BiquadAttrs>);
+    let attrs = input.get_attrs();
+    let ty = attrs.ty.clone();
+    let coefficients = biquad::get_coefficients(attrs);
+    let values = match to_coefficient_literals(
+        &ty,
+        &coefficients,
+        &attrs.fraction,
+        2,
+        attrs.frequency.span(),
+    ) {
+        Ok(values) => values,
+        Err(error) => return error.into_compile_error().into(),
+    };
+    let tokens = array(values, 5);
+    let float = ty.is_float();
+    let ty = ty.path();
+    input.expand(quote!([#ty; 5]), tokens, float)
+}
+
+/// Generates Goertzel algorithm coefficients for detecting tones.
+///
+/// The coefficients are `2·cos(2πk/N)`, where `N` is the block size given with `block` and `k`
+/// is the frequency bin of each of the frequencies given in Hz with `frequencies`. Both of them
+/// are required. `rate` specifies the sampling rate and defaults to 44,100 Hz like with
+/// [sine_wave!]. The frequencies are validated like `frequency` of [sine_wave!].
+///
+/// The bins are matched to the tones that [sine_wave!] generates. As the generated waves have a
+/// whole number of samples per period, a tone of `frequency` has an actual frequency of
+/// `rate / floor(rate / frequency)`, and the bin nearest to that is used. Every frequency must
+/// fall between bins 1 and `N / 2` and no two frequencies may fall to the same bin. Choosing
+/// `block` as a multiple of the length of the wave makes the tone land exactly on its bin.
+///
+/// `type` and `fraction` work like with [biquad!]: integer types are treated as fixed point
+/// numbers with `fraction` fraction bits, which defaults to two bits less than the size of the
+/// type, e.g. Q2.14 for [`i16`]. Floating point types are emitted as is.
+///
+/// The same syntax for [const and static items][crate::sine_wave!#use-with-static-and-const] is
+/// also supported with `goertzel` as the identifier.
+///
+/// # Examples
+/// ```rust
+/// # use sine_macro::goertzel;
+/// // Coefficients for DTMF row and column tones with 20 ms blocks
+/// let dtmf = goertzel!(
+///     rate: 44_100,
+///     block: 882,
+///     frequencies: [697, 770, 852, 941, 1209, 1336, 1477, 1633],
+/// );
+///
+/// // Detector for the 440 Hz tone of `sine_wave!(frequency: 440, rate: 48_000)`, which is 109
+/// // samples long, as f32 coefficient
+/// let [tone] = goertzel!(rate: 48_000, block: 1_090, frequencies: [440], type: f32);
+/// ```
+///
+/// And as a const item:
+/// ```rust
+/// # use sine_macro::goertzel;
+/// goertzel! {
+///     const PILOT = goertzel(rate: 48_000, block: 480, frequencies: [1_000], type: i32);
+/// }
+/// ```
+#[proc_macro]
+pub fn goertzel(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as Input<GoertzelAttrs>);
+    let attrs = input.get_attrs();
+    let ty = attrs.ty.clone();
+    let coefficients = goertzel::get_coefficients(attrs);
+    let span = attrs
+        .fraction
+        .as_ref()
+        .map(|fraction| fraction.span())
+        .unwrap_or_else(Span::call_site);
+    let values = match to_coefficient_literals(&ty, &coefficients, &attrs.fraction, 2, span) {
+        Ok(values) => values,
+        Err(error) => return error.into_compile_error().into(),
+    };
+    let count = values.len();
+    let tokens = array(values, count);
+    let float = ty.is_float();
+    let ty = ty.path();
+    input.expand(quote!([#ty; #count]), tokens, float)
+}
+
+/// Generates twiddle factor and bit-reversal tables for a radix-2 FFT.
+///
+/// `size` is the size of the FFT, which must be a power of two between 4 and 1,048,576, and is
+/// required. The macro returns a tuple of twiddle factor table and bit-reversal table.
+///
+/// The twiddle factor table contains `e^(-2πik/N)` for `k` in `0..N/2`, where `N` is `size`. Each
+/// of them is given as a pair of `cos(2πk/N)` and `-sin(2πk/N)`, i.e. the real and imaginary
+/// parts. Both are sampled from one period of the same sine wave as [sine_wave!] generates.
+/// `layout` selects how they are laid out: with `interleaved`, the default, the table is an array
+/// of `N` values with real and imaginary parts interleaved, and with `pairs` the table is an
+/// array of `N/2` tuples.
+///
+/// `type` can be any of the types supported by [sine_wave!] and the values are scaled like with
+/// [sine_wave!], e.g. [`i16`] values are Q15 fixed point numbers, but they are rounded to the
+/// nearest value. Floating point types are emitted as is.
+///
+/// The bit-reversal table contains the bit-reversed index for each of the `N` indices and its
+/// type is the smallest unsigned integer type that fits all indices, i.e. [`u8`] for sizes up to
+/// 256, [`u16`] for sizes up to 65,536 and [`u32`] otherwise.
+///
+/// The same syntax for [const and static items][crate::sine_wave!#use-with-static-and-const] is
+/// also supported with `twiddles` as the identifier.
+///
+/// # Examples
+/// ```rust
+/// # use sine_macro::twiddles;
+/// // Q15 twiddle factors for 256 point FFT
+/// let (twiddles, bit_reversal): ([i16; 256], [u8; 256]) = twiddles!(size: 256);
+///
+/// // Twiddle factors as complex pairs
+/// let (twiddles, bit_reversal) = twiddles!(size: 1024, layout: pairs, type: f32);
+/// let (re, im): (f32, f32) = twiddles[256];
+/// ```
+///
+/// And as a static item:
+/// ```rust
+/// # use sine_macro::twiddles;
+/// twiddles! {
+///     static FFT_4096 = twiddles(size: 4096, type: i32);
+/// }
+/// let (twiddles, bit_reversal) = &FFT_4096;
+/// ```
+#[proc_macro]
+pub fn twiddles(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as Input<TwiddlesAttrs>);
+    let attrs = input.get_attrs();
+    let ty = attrs.ty.clone();
+    let size = attrs.size;
+    let (re, im): (Vec<_>, Vec<_>) = get_twiddles(size).into_iter().unzip();
+    let min = ty.min(FullScale::Symmetric);
+    let re = to_literals(&ty, &re, min, Rounding::Nearest, None);
+    let im = to_literals(&ty, &im, min, Rounding::Nearest, None);
+    let ident = ty.path();
+    let (twiddles_ty, twiddles) = match attrs.layout.unwrap_or(Layout::Interleaved) {
+        Layout::Interleaved => (
+            quote!([#ident; #size]),
+            array(re.into_iter().interleave(im), size),
+        ),
+        Layout::Pairs => {
+            let count = size / 2;
+            let pairs = re
+                .into_iter()
+                .zip(im)
+                .map(|(re, im)| Group::new(Delimiter::Parenthesis, quote!(#re, #im)));
+            (quote!([(#ident, #ident); #count]), array(pairs, count))
+        }
+    };
+    let bit_reversal = get_bit_reversal(size);
+    let (index_ty, indices) = if size <= 1 << u8::BITS {
+        let values = bit_reversal
+            .into_iter()
+            .map(|index| proc_macro2::Literal::u8_suffixed(index as u8));
+        (quote!(u8), array(values, size))
+    } else if size <= 1 << u16::BITS {
+        let values = bit_reversal
+            .into_iter()
+            .map(|index| proc_macro2::Literal::u16_suffixed(index as u16));
+        (quote!(u16), array(values, size))
+    } else {
+        let values = bit_reversal
+            .into_iter()
+            .map(|index| proc_macro2::Literal::u32_suffixed(index as u32));
+        (quote!(u32), array(values, size))
+    };
+    input.expand(
+        quote!((#twiddles_ty, [#index_ty; #size])),
+        quote!((#twiddles, #indices)),
+        ty.is_float(),
+    )
 }
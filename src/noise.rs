@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::items::Attrs;
+use crate::types::{Type, parse_int};
+use crate::{check_signed, set_once};
+use sine_macro_core::SplitMix64;
+use std::num::{NonZeroU64, NonZeroUsize};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitInt, Result, Token};
+
+/// Feedback taps of the 64-bit Galois LFSR, i.e. x^64 + x^63 + x^61 + x^60 + 1.
+const LFSR_TAPS: u64 = 0xD800_0000_0000_0000;
+
+#[derive(Clone, Copy)]
+pub(crate) enum NoiseKind {
+    White,
+    Pink,
+    Lfsr,
+}
+
+impl Parse for NoiseKind {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "white" => Ok(NoiseKind::White),
+            "pink" => Ok(NoiseKind::Pink),
+            "lfsr" => Ok(NoiseKind::Lfsr),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `kind`, must be one of `white`, `pink` and `lfsr`",
+            )),
+        }
+    }
+}
+
+enum NoiseAttrInput {
+    Seed(Ident, LitInt),
+    Len(Ident, LitInt),
+    Kind(Ident, NoiseKind),
+    Type(Token![type], Type),
+}
+
+impl Parse for NoiseAttrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![type]) {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            return Ok(NoiseAttrInput::Type(name, input.parse()?));
+        }
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        match name.to_string().as_ref() {
+            "seed" => Ok(NoiseAttrInput::Seed(name.clone(), parse_int(input, &name)?)),
+            "len" => Ok(NoiseAttrInput::Len(name.clone(), parse_int(input, &name)?)),
+            "kind" => Ok(NoiseAttrInput::Kind(name, input.parse()?)),
+            _ => Err(Error::new(
+                name.span(),
+                "invalid identifier, must be one of `seed`, `len`, `kind` and `type`",
+            )),
+        }
+    }
+}
+
+pub(crate) struct NoiseAttrs {
+    pub seed: LitInt,
+    pub len: LitInt,
+    pub kind: Option<NoiseKind>,
+    pub ty: Option<Type>,
+}
+
+impl Parse for NoiseAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<NoiseAttrInput, Token![,]>::parse_terminated(input)?;
+        let mut seed = None;
+        let mut len = None;
+        let mut kind = None;
+        let mut ty = None;
+        for attr in attrs {
+            match attr {
+                NoiseAttrInput::Seed(name, attr_value) => {
+                    set_once(&mut seed, &name, attr_value.clone())?;
+                    let _value: NonZeroU64 = attr_value.base10_parse()?;
+                }
+                NoiseAttrInput::Len(name, attr_value) => {
+                    set_once(&mut len, &name, attr_value.clone())?;
+                    let _value: NonZeroUsize = attr_value.base10_parse()?;
+                }
+                NoiseAttrInput::Kind(name, attr_value) => set_once(&mut kind, &name, attr_value)?,
+                NoiseAttrInput::Type(name, attr_value) => set_once(&mut ty, &name, attr_value)?,
+            }
+        }
+        let Some(seed) = seed else {
+            return Err(Error::new(input.span(), "`seed` must be defined"));
+        };
+        let Some(len) = len else {
+            return Err(Error::new(input.span(), "`len` must be defined"));
+        };
+        check_signed(&ty)?;
+        Ok(NoiseAttrs {
+            seed,
+            len,
+            kind,
+            ty,
+        })
+    }
+}
+
+impl Attrs for NoiseAttrs {
+    const NAME: &'static str = "noise";
+}
+
+fn get_white_noise(seed: u64, len: usize) -> Vec<f64> {
+    let mut rng = SplitMix64::new(seed);
+    (0..len).map(|_| rng.next_f64()).collect()
+}
+
+/// Filters white noise to pink noise with Paul Kellett's refined method and normalizes it to
+/// peak at full scale.
+fn get_pink_noise(seed: u64, len: usize) -> Vec<f64> {
+    let mut b = [0_f64; 7];
+    let noise: Vec<_> = get_white_noise(seed, len)
+        .into_iter()
+        .map(|white| {
+            b[0] = 0.99886 * b[0] + white * 0.0555179;
+            b[1] = 0.99332 * b[1] + white * 0.0750759;
+            b[2] = 0.96900 * b[2] + white * 0.1538520;
+            b[3] = 0.86650 * b[3] + white * 0.3104856;
+            b[4] = 0.55000 * b[4] + white * 0.5329522;
+            b[5] = -0.7616 * b[5] - white * 0.0168980;
+            let pink = b.iter().sum::<f64>() + white * 0.5362;
+            b[6] = white * 0.115926;
+            pink
+        })
+        .collect();
+    let peak = noise
+        .iter()
+        .fold(0_f64, |peak, value| peak.max(value.abs()));
+    if peak > 0_f64 {
+        noise.into_iter().map(|value| value / peak).collect()
+    } else {
+        noise
+    }
+}
+
+fn get_lfsr_noise(seed: u64, len: usize) -> Vec<f64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            let bit = state & 1;
+            state >>= 1;
+            if bit == 1 {
+                state ^= LFSR_TAPS;
+                1_f64
+            } else {
+                -1_f64
+            }
+        })
+        .collect()
+}
+
+/// Returns `len` samples of noise in range `[-1, 1]`.
+pub(crate) fn get_noise(kind: Option<NoiseKind>, seed: NonZeroU64, len: usize) -> Vec<f64> {
+    match kind.unwrap_or(NoiseKind::White) {
+        NoiseKind::White => get_white_noise(seed.get(), len),
+        NoiseKind::Pink => get_pink_noise(seed.get(), len),
+        NoiseKind::Lfsr => get_lfsr_noise(seed.get(), len),
+    }
+}
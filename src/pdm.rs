@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::get_number_of_samples;
+use crate::types::BitOrder;
+use sine_macro_core::gcd;
+
+/// Returns one period of sine wave sampled `oversampling` times for every sample that
+/// [sine_wave!] would generate for `rate`.
+///
+/// Sampling the wave directly at the higher rate avoids interpolating between the samples. The
+/// period is sampled like one period of 1 Hz at a rate of its length, which is exact at every
+/// quarter like the other waves.
+fn get_oversampled_wave(frequency: u32, rate: u32, oversampling: usize) -> Vec<f64> {
+    let values = get_number_of_samples(frequency, rate) * oversampling;
+    let values = u32::try_from(values).expect("too many samples for `pdm`");
+    sine_macro_core::sine_wave(1, values)
+}
+
+/// Runs a first order sigma-delta modulator over `periods` periods of the wave.
+///
+/// The modulator is first run over one period to let the integrator settle, so that the
+/// bitstream continues smoothly when it is looped.
+fn get_bitstream(wave: &[f64], periods: usize) -> Vec<bool> {
+    let mut integrator = 0_f64;
+    let mut feedback = 0_f64;
+    let mut bits: Vec<_> = wave
+        .iter()
+        .cycle()
+        .take(wave.len() * (periods + 1))
+        .map(|value| {
+            integrator += value - feedback;
+            let bit = integrator >= 0_f64;
+            feedback = if bit { 1_f64 } else { -1_f64 };
+            bit
+        })
+        .collect();
+    bits.drain(..wave.len());
+    bits
+}
+
+/// Packs the bits into words of `word_bits` bits.
+fn pack(bits: &[bool], word_bits: u32, order: BitOrder) -> Vec<i64> {
+    bits.chunks(word_bits as usize)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .filter(|(_, bit)| **bit)
+                .map(|(i, _)| match order {
+                    BitOrder::Msb => 1 << (word_bits as usize - 1 - i),
+                    BitOrder::Lsb => 1 << i,
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Returns the pulse-density modulated bitstream of sine wave packed into words of `word_bits`
+/// bits.
+///
+/// The bitstream covers as many periods of the wave as needed for it to fill whole words.
+pub(crate) fn get_pdm_words(
+    frequency: u32,
+    rate: u32,
+    oversampling: usize,
+    word_bits: u32,
+    order: BitOrder,
+) -> Vec<i64> {
+    let wave = get_oversampled_wave(frequency, rate, oversampling);
+    let periods = word_bits / gcd(wave.len() as u32, word_bits);
+    pack(&get_bitstream(&wave, periods as usize), word_bits, order)
+}
@@ -0,0 +1,128 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::items::Attrs;
+use crate::types::{Type, parse_int};
+use crate::{check_signed, set_once};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitInt, Result, Token};
+
+/// The largest supported FFT size.
+const MAX_SIZE: usize = 1 << 20;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Layout {
+    Interleaved,
+    Pairs,
+}
+
+impl Parse for Layout {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "interleaved" => Ok(Layout::Interleaved),
+            "pairs" => Ok(Layout::Pairs),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `layout`, must be one of `interleaved` and `pairs`",
+            )),
+        }
+    }
+}
+
+enum TwiddlesAttrInput {
+    Size(Ident, LitInt),
+    Layout(Ident, Layout),
+    Type(Token![type], Type),
+}
+
+impl Parse for TwiddlesAttrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![type]) {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            return Ok(TwiddlesAttrInput::Type(name, input.parse()?));
+        }
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        match name.to_string().as_ref() {
+            "size" => Ok(TwiddlesAttrInput::Size(
+                name.clone(),
+                parse_int(input, &name)?,
+            )),
+            "layout" => Ok(TwiddlesAttrInput::Layout(name, input.parse()?)),
+            _ => Err(Error::new(
+                name.span(),
+                "invalid identifier, must be one of `size`, `layout` and `type`",
+            )),
+        }
+    }
+}
+
+pub(crate) struct TwiddlesAttrs {
+    pub size: usize,
+    pub layout: Option<Layout>,
+    pub ty: Option<Type>,
+}
+
+impl Parse for TwiddlesAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<TwiddlesAttrInput, Token![,]>::parse_terminated(input)?;
+        let mut size = None;
+        let mut layout = None;
+        let mut ty = None;
+        for attr in attrs {
+            match attr {
+                TwiddlesAttrInput::Size(name, attr_value) => {
+                    set_once(&mut size, &name, attr_value.clone())?;
+                    let value: usize = attr_value.base10_parse()?;
+                    if !value.is_power_of_two() || !(4..=MAX_SIZE).contains(&value) {
+                        return Err(Error::new_spanned(
+                            attr_value,
+                            format_args!(
+                                "`size` must be a power of two between 4 and {}",
+                                MAX_SIZE
+                            ),
+                        ));
+                    }
+                }
+                TwiddlesAttrInput::Layout(name, attr_value) => {
+                    set_once(&mut layout, &name, attr_value)?
+                }
+                TwiddlesAttrInput::Type(name, attr_value) => set_once(&mut ty, &name, attr_value)?,
+            }
+        }
+        let Some(size) = size else {
+            return Err(Error::new(input.span(), "`size` must be defined"));
+        };
+        let size = size.base10_parse().unwrap();
+        check_signed(&ty)?;
+        Ok(TwiddlesAttrs { size, layout, ty })
+    }
+}
+
+impl Attrs for TwiddlesAttrs {
+    const NAME: &'static str = "twiddles";
+}
+
+/// Returns the real and imaginary parts of twiddle factors `e^(-2πik/N)` for `k` in `0..N/2`.
+///
+/// Both of them are taken from one period of sine wave of `N` samples, as cosine is sine shifted
+/// by a quarter of the period.
+pub(crate) fn get_twiddles(size: usize) -> Vec<(f64, f64)> {
+    let wave = sine_macro_core::sine_wave(1, size as u32);
+    (0..size / 2)
+        .map(|k| (wave[(k + size / 4) % size], 0_f64 - wave[k]))
+        .collect()
+}
+
+/// Returns the bit-reversed index for every index of an FFT of `size`.
+pub(crate) fn get_bit_reversal(size: usize) -> Vec<usize> {
+    let bits = size.trailing_zeros();
+    (0..size)
+        .map(|index| index.reverse_bits() >> (usize::BITS - bits))
+        .collect()
+}
@@ -3,8 +3,12 @@
  * SPDX-License-Identifier: MIT
  */
 
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use sine_macro_core::args::{FULL_SCALES, MODULATIONS, ROUNDINGS};
+use std::fmt::Display;
 use syn::parse::{Error, Parse, ParseStream};
-use syn::{Ident, LitInt, Result, Token};
+use syn::{BinOp, Expr, Ident, Lit, LitInt, Path, Result, Token, UnOp};
 
 pub(crate) enum Int {
     Frequency(LitInt),
@@ -12,13 +16,119 @@ pub(crate) enum Int {
     Len(LitInt),
     Repeats(LitInt),
     Skip(LitInt),
+    Modulator(LitInt),
+    Depth(LitInt),
+    Deviation(LitInt),
+    Dither(LitInt),
+    Phases(LitInt),
+    PwmTop(LitInt),
+    DeadTime(LitInt),
+    Pdm(LitInt),
 }
 
 #[derive(Clone)]
 pub(crate) enum Type {
     I8(Ident),
     I16(Ident),
+    I24(Ident),
     I32(Ident),
+    I64(Ident),
+    I128(Ident),
+    U8(Ident),
+    U16(Ident),
+    U32(Ident),
+    F32(Ident),
+    F64(Ident),
+    #[cfg(feature = "half")]
+    F16(Ident),
+    /// Signed fixed point type of the `fixed` crate, e.g. `I1F15`.
+    #[cfg(feature = "fixed")]
+    Fixed {
+        ident: Ident,
+        bits: u32,
+        fraction: u32,
+    },
+    /// Any other type whose values are `storage` values wrapped with `wrap`.
+    Custom(Box<CustomType>),
+}
+
+#[derive(Clone)]
+pub(crate) struct CustomType {
+    pub path: Path,
+    pub storage: Type,
+    pub wrap: Path,
+}
+
+/// Value of `type`, which is either one of the supported types or a path to any other type.
+pub(crate) enum TypeInput {
+    Type(Type),
+    Path(Path),
+}
+
+#[derive(Clone)]
+pub(crate) enum Modulation {
+    Am(Ident),
+    Fm(Ident),
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Rounding {
+    Truncate,
+    Nearest,
+    Floor,
+    Ceil,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum FullScale {
+    Symmetric,
+    Asymmetric,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Quadrature {
+    Tables,
+    Pairs,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Shape {
+    Sine,
+    ThirdHarmonic,
+    SpaceVector,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum PhaseLayout {
+    Tables,
+    Frames,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum BitOrder {
+    Msb,
+    Lsb,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum ByteOrder {
+    Little,
+    Big,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Justify {
+    Left,
+    Right,
+}
+
+/// Number that can be given either as an integer or as a floating point literal, optionally
+/// negated.
+#[derive(Clone)]
+pub(crate) struct Number {
+    neg: Option<Token![-]>,
+    lit: Lit,
+    pub value: f64,
 }
 
 pub(crate) struct IntAttrInput {
@@ -30,29 +140,388 @@ pub(crate) struct IntAttrInput {
 pub(crate) struct TypeAttrInput {
     pub name: Token![type],
     _sep: Token![:],
-    pub value: Type,
+    pub value: TypeInput,
+}
+
+/// Attribute whose value is a constant expression that is resolved by the compiler.
+pub(crate) struct ConstAttrInput {
+    pub name: Ident,
+    _sep: Token![:],
+    pub value: Expr,
+}
+
+/// Attribute that takes a path as its value.
+pub(crate) struct PathAttrInput {
+    pub name: Ident,
+    _sep: Token![:],
+    pub value: Path,
+}
+
+/// Attribute that takes one of predefined identifiers as its value.
+pub(crate) struct ChoiceAttrInput<T> {
+    pub name: Ident,
+    _sep: Token![:],
+    pub value: T,
 }
 
 pub(crate) enum AttrInput {
     Int(IntAttrInput),
     Type(TypeAttrInput),
+    Modulation(ChoiceAttrInput<Modulation>),
+    Rounding(ChoiceAttrInput<Rounding>),
+    FullScale(ChoiceAttrInput<FullScale>),
+    Quadrature(ChoiceAttrInput<Quadrature>),
+    Shape(ChoiceAttrInput<Shape>),
+    Layout(ChoiceAttrInput<PhaseLayout>),
+    BitOrder(ChoiceAttrInput<BitOrder>),
+    Bytes(ChoiceAttrInput<ByteOrder>),
+    Justify(ChoiceAttrInput<Justify>),
+    Storage(ChoiceAttrInput<Type>),
+    Wrap(PathAttrInput),
+    Const(ConstAttrInput),
+}
+
+/// Integer value that is either known when the macro is expanded or a constant expression.
+pub(crate) enum IntExpr {
+    Lit(LitInt),
+    Const(Expr),
+}
+
+impl IntExpr {
+    /// Parses the value of the argument called `name`.
+    ///
+    /// Expressions are evaluated here, so the results that cannot be values of any argument are
+    /// reported here with the whole expression as the span.
+    fn parse(input: ParseStream, name: impl Display) -> Result<Self> {
+        // Plain literals are kept as they are to retain their suffixes and error messages
+        let fork = input.fork();
+        if fork.parse::<LitInt>().is_ok() && (fork.is_empty() || fork.peek(Token![,])) {
+            let lit: LitInt = input.parse()?;
+            if lit.base10_parse::<i128>().is_ok_and(|value| value < 0) {
+                return Err(Error::new_spanned(
+                    lit,
+                    sine_macro_core::Error::Negative(name.to_string()),
+                ));
+            }
+            return Ok(IntExpr::Lit(lit));
+        }
+        let expr: Expr = input.parse()?;
+        match evaluate(&expr)? {
+            Some(value) if value < 0 => Err(Error::new_spanned(
+                expr,
+                sine_macro_core::Error::Negative(name.to_string()),
+            )),
+            Some(value) if u64::try_from(value).is_err() => Err(Error::new_spanned(
+                expr,
+                "number too large to fit in target type",
+            )),
+            Some(value) => Ok(IntExpr::Lit(LitInt::new(
+                &value.to_string(),
+                syn::spanned::Spanned::span(&expr),
+            ))),
+            None => Ok(IntExpr::Const(expr)),
+        }
+    }
+}
+
+/// Parses an integer literal or an arithmetic expression of integer literals as the value of
+/// the argument called `name`.
+pub(crate) fn parse_int(input: ParseStream, name: impl Display) -> Result<LitInt> {
+    match IntExpr::parse(input, name)? {
+        IntExpr::Lit(lit) => Ok(lit),
+        IntExpr::Const(expr) => Err(Error::new_spanned(
+            expr,
+            "expected an integer literal or an arithmetic expression of integer literals",
+        )),
+    }
+}
+
+/// Evaluates an arithmetic expression of integer literals.
+///
+/// Returns `None` if the expression contains anything else, such as paths to constants.
+fn evaluate(expr: &Expr) -> Result<Option<i128>> {
+    let value = match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse()?,
+        Expr::Paren(expr) => return evaluate(&expr.expr),
+        Expr::Group(expr) => return evaluate(&expr.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            let Some(value) = evaluate(&unary.expr)? else {
+                return Ok(None);
+            };
+            -value
+        }
+        Expr::Binary(binary) => {
+            let (Some(left), Some(right)) = (evaluate(&binary.left)?, evaluate(&binary.right)?)
+            else {
+                return Ok(None);
+            };
+            let value = match binary.op {
+                BinOp::Add(_) => left.checked_add(right),
+                BinOp::Sub(_) => left.checked_sub(right),
+                BinOp::Mul(_) => left.checked_mul(right),
+                BinOp::Div(_) if right == 0 => {
+                    return Err(Error::new_spanned(expr, "attempt to divide by zero"));
+                }
+                BinOp::Div(_) => left.checked_div(right),
+                BinOp::Rem(_) if right == 0 => {
+                    return Err(Error::new_spanned(
+                        expr,
+                        "attempt to calculate the remainder with a divisor of zero",
+                    ));
+                }
+                BinOp::Rem(_) => left.checked_rem(right),
+                _ => return Ok(None),
+            };
+            let Some(value) = value else {
+                return Err(Error::new_spanned(expr, "arithmetic operation overflowed"));
+            };
+            value
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+impl Type {
+    /// Returns the supported type that is named by the identifier.
+    pub(crate) fn from_ident(value: &Ident) -> Option<Self> {
+        match value.to_string().as_ref() {
+            "i8" => Some(Type::I8(value.clone())),
+            "i16" => Some(Type::I16(value.clone())),
+            "i24" => Some(Type::I24(value.clone())),
+            "i32" => Some(Type::I32(value.clone())),
+            "i64" => Some(Type::I64(value.clone())),
+            "i128" => Some(Type::I128(value.clone())),
+            "u8" => Some(Type::U8(value.clone())),
+            "u16" => Some(Type::U16(value.clone())),
+            "u32" => Some(Type::U32(value.clone())),
+            "f32" => Some(Type::F32(value.clone())),
+            "f64" => Some(Type::F64(value.clone())),
+            #[cfg(feature = "half")]
+            "f16" => Some(Type::F16(value.clone())),
+            #[cfg(feature = "fixed")]
+            name => parse_fixed(name).map(|(bits, fraction)| Type::Fixed {
+                ident: value.clone(),
+                bits,
+                fraction,
+            }),
+            #[cfg(not(feature = "fixed"))]
+            _ => None,
+        }
+    }
+}
+
+/// Parses one of the supported types as the value of `name`.
+fn parse_type(input: ParseStream, name: &'static str) -> Result<Type> {
+    let value: Ident = input.parse()?;
+    Type::from_ident(&value).ok_or_else(|| {
+        Error::new_spanned(
+            value,
+            sine_macro_core::Error::InvalidType {
+                name,
+                storage: false,
+            },
+        )
+    })
 }
 
 impl Parse for Type {
+    fn parse(input: ParseStream) -> Result<Self> {
+        parse_type(input, "type")
+    }
+}
+
+impl Parse for TypeInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path: Path = input.parse()?;
+        match path.get_ident().and_then(Type::from_ident) {
+            Some(ty) => Ok(TypeInput::Type(ty)),
+            None => Ok(TypeInput::Path(path)),
+        }
+    }
+}
+
+/// Parses the total and fraction bits from the name of a signed type of the `fixed` crate.
+#[cfg(feature = "fixed")]
+fn parse_fixed(name: &str) -> Option<(u32, u32)> {
+    let (integer, fraction) = name.strip_prefix('I')?.split_once('F')?;
+    // Reject signs, which `parse` would accept
+    if [integer, fraction]
+        .iter()
+        .any(|part| part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+    let (integer, fraction): (u32, u32) = (integer.parse().ok()?, fraction.parse().ok()?);
+    let bits = integer.checked_add(fraction)?;
+    [8, 16, 32, 64, 128]
+        .contains(&bits)
+        .then_some((bits, fraction))
+}
+
+impl Parse for Modulation {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "am" => Ok(Modulation::Am(value)),
+            "fm" => Ok(Modulation::Fm(value)),
+            _ => Err(Error::new_spanned(
+                value,
+                sine_macro_core::Error::InvalidChoice("modulation", MODULATIONS),
+            )),
+        }
+    }
+}
+
+impl Parse for Rounding {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "truncate" => Ok(Rounding::Truncate),
+            "nearest" => Ok(Rounding::Nearest),
+            "floor" => Ok(Rounding::Floor),
+            "ceil" => Ok(Rounding::Ceil),
+            _ => Err(Error::new_spanned(
+                value,
+                sine_macro_core::Error::InvalidChoice("rounding", ROUNDINGS),
+            )),
+        }
+    }
+}
+
+impl From<Rounding> for sine_macro_core::Rounding {
+    fn from(value: Rounding) -> Self {
+        match value {
+            Rounding::Truncate => sine_macro_core::Rounding::Truncate,
+            Rounding::Nearest => sine_macro_core::Rounding::Nearest,
+            Rounding::Floor => sine_macro_core::Rounding::Floor,
+            Rounding::Ceil => sine_macro_core::Rounding::Ceil,
+        }
+    }
+}
+
+impl Parse for FullScale {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "symmetric" => Ok(FullScale::Symmetric),
+            "asymmetric" => Ok(FullScale::Asymmetric),
+            _ => Err(Error::new_spanned(
+                value,
+                sine_macro_core::Error::InvalidChoice("full_scale", FULL_SCALES),
+            )),
+        }
+    }
+}
+
+impl Parse for Quadrature {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "tables" => Ok(Quadrature::Tables),
+            "pairs" => Ok(Quadrature::Pairs),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `quadrature`, must be one of `tables` and `pairs`",
+            )),
+        }
+    }
+}
+
+impl Parse for Shape {
     fn parse(input: ParseStream) -> Result<Self> {
         let value: Ident = input.parse()?;
         match value.to_string().as_ref() {
-            "i8" => Ok(Type::I8(value)),
-            "i16" => Ok(Type::I16(value)),
-            "i32" => Ok(Type::I32(value)),
+            "sine" => Ok(Shape::Sine),
+            "third_harmonic" => Ok(Shape::ThirdHarmonic),
+            "space_vector" => Ok(Shape::SpaceVector),
             _ => Err(Error::new_spanned(
                 value,
-                "invalid value for `type`, must be one of `i8`, `i16` and `i32`",
+                "invalid value for `shape`, must be one of `sine`, `third_harmonic` and `space_vector`",
             )),
         }
     }
 }
 
+impl Parse for PhaseLayout {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "tables" => Ok(PhaseLayout::Tables),
+            "frames" => Ok(PhaseLayout::Frames),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `layout`, must be one of `tables` and `frames`",
+            )),
+        }
+    }
+}
+
+impl Parse for BitOrder {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "msb" => Ok(BitOrder::Msb),
+            "lsb" => Ok(BitOrder::Lsb),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `bit_order`, must be one of `msb` and `lsb`",
+            )),
+        }
+    }
+}
+
+impl Parse for ByteOrder {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "le" => Ok(ByteOrder::Little),
+            "be" => Ok(ByteOrder::Big),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `bytes`, must be one of `le` and `be`",
+            )),
+        }
+    }
+}
+
+impl Parse for Justify {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "left" => Ok(Justify::Left),
+            "right" => Ok(Justify::Right),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `justify`, must be one of `left` and `right`",
+            )),
+        }
+    }
+}
+
+impl Parse for Number {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let neg: Option<Token![-]> = input.parse()?;
+        let lit: Lit = input.parse()?;
+        let value: f64 = match &lit {
+            Lit::Int(value) => value.base10_parse()?,
+            Lit::Float(value) => value.base10_parse()?,
+            _ => return Err(Error::new_spanned(lit, "expected a number")),
+        };
+        let value = if neg.is_some() { -value } else { value };
+        Ok(Number { neg, lit, value })
+    }
+}
+
+impl ToTokens for Number {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.neg.to_tokens(tokens);
+        self.lit.to_tokens(tokens)
+    }
+}
+
 impl Parse for AttrInput {
     fn parse(input: ParseStream) -> Result<Self> {
         let lookahead = input.lookahead1();
@@ -64,33 +533,132 @@ impl Parse for AttrInput {
             }))
         } else {
             let name: Ident = input.parse()?;
-            Ok(AttrInput::Int(IntAttrInput {
-                name: name.clone(),
-                _sep: input.parse()?,
-                value: match name.to_string().as_ref() {
-                    "frequency" => input.parse().map(Int::Frequency)?,
-                    "rate" => input.parse().map(Int::Rate)?,
-                    "len" => input.parse().map(Int::Len)?,
-                    "repeats" => input.parse().map(Int::Repeats)?,
-                    "skip" => input.parse().map(Int::Skip)?,
-                    _ => {
-                        return Err(Error::new(
-                            name.span(),
-                            "invalid identifier, must be one of `frequency`, `rate`, `len`, `repeats`, `skip` and `type`",
-                        ));
-                    }
+            let sep = input.parse()?;
+            match name.to_string().as_ref() {
+                "modulation" => Ok(AttrInput::Modulation(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "rounding" => Ok(AttrInput::Rounding(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "full_scale" => Ok(AttrInput::FullScale(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "quadrature" => Ok(AttrInput::Quadrature(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "shape" => Ok(AttrInput::Shape(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "layout" => Ok(AttrInput::Layout(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "bit_order" => Ok(AttrInput::BitOrder(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "bytes" => Ok(AttrInput::Bytes(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "justify" => Ok(AttrInput::Justify(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                "storage" => Ok(AttrInput::Storage(ChoiceAttrInput {
+                    name,
+                    _sep: sep,
+                    value: parse_type(input, "storage")?,
+                })),
+                "wrap" => Ok(AttrInput::Wrap(PathAttrInput {
+                    name,
+                    _sep: sep,
+                    value: input.parse()?,
+                })),
+                // These can also be constants that are resolved by the compiler
+                "frequency" | "rate" => match IntExpr::parse(input, &name)? {
+                    IntExpr::Lit(lit) => Ok(AttrInput::Int(IntAttrInput {
+                        value: match name.to_string().as_ref() {
+                            "frequency" => Int::Frequency(lit),
+                            _ => Int::Rate(lit),
+                        },
+                        name,
+                        _sep: sep,
+                    })),
+                    IntExpr::Const(expr) => Ok(AttrInput::Const(ConstAttrInput {
+                        name,
+                        _sep: sep,
+                        value: expr,
+                    })),
                 },
-            }))
+                _ => Ok(AttrInput::Int(IntAttrInput {
+                    name: name.clone(),
+                    _sep: sep,
+                    value: match name.to_string().as_ref() {
+                        "len" => parse_int(input, &name).map(Int::Len)?,
+                        "repeats" => parse_int(input, &name).map(Int::Repeats)?,
+                        "skip" => parse_int(input, &name).map(Int::Skip)?,
+                        "modulator" => parse_int(input, &name).map(Int::Modulator)?,
+                        "depth" => parse_int(input, &name).map(Int::Depth)?,
+                        "deviation" => parse_int(input, &name).map(Int::Deviation)?,
+                        "dither" => parse_int(input, &name).map(Int::Dither)?,
+                        "phases" => parse_int(input, &name).map(Int::Phases)?,
+                        "pwm_top" => parse_int(input, &name).map(Int::PwmTop)?,
+                        "dead_time" => parse_int(input, &name).map(Int::DeadTime)?,
+                        "pdm" => parse_int(input, &name).map(Int::Pdm)?,
+                        _ => {
+                            return Err(Error::new(
+                                name.span(),
+                                sine_macro_core::Error::InvalidIdentifier,
+                            ));
+                        }
+                    },
+                })),
+            }
         }
     }
 }
 
 pub(crate) mod helpers {
-    use crate::types::Type;
-    use proc_macro2::Span;
+    use crate::types::{ByteOrder, FullScale, Modulation, Type};
+    use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+    use quote::{ToTokens, quote};
 
     pub(crate) trait Ident {
+        /// Returns the identifier of the Rust type that holds the values.
         fn ident(&self) -> syn::Ident;
+
+        /// Returns the name of the type as it was given.
+        fn name(&self) -> String {
+            self.ident().to_string()
+        }
+
+        /// Returns the path of the Rust type that holds the values.
+        fn path(&self) -> TokenStream {
+            self.ident().into_token_stream()
+        }
+    }
+
+    /// Wraps the value in a call to a constructor of a type that is not a primitive.
+    ///
+    /// The group is invisible so that the constructor can be used like a literal.
+    fn construct(constructor: TokenStream, value: impl ToTokens) -> TokenTree {
+        Group::new(Delimiter::None, quote!(#constructor(#value))).into()
     }
 
     impl Ident for Type {
@@ -98,7 +666,49 @@ pub(crate) mod helpers {
             match self {
                 Self::I8(ident) => ident.clone(),
                 Self::I16(ident) => ident.clone(),
+                // 24-bit values are stored in `i32`
+                Self::I24(ident) => syn::Ident::new("i32", ident.span()),
                 Self::I32(ident) => ident.clone(),
+                Self::I64(ident) => ident.clone(),
+                Self::I128(ident) => ident.clone(),
+                Self::U8(ident) => ident.clone(),
+                Self::U16(ident) => ident.clone(),
+                Self::U32(ident) => ident.clone(),
+                Self::F32(ident) => ident.clone(),
+                Self::F64(ident) => ident.clone(),
+                #[cfg(feature = "half")]
+                Self::F16(ident) => ident.clone(),
+                #[cfg(feature = "fixed")]
+                Self::Fixed { ident, .. } => ident.clone(),
+                Self::Custom(custom) => custom.storage.ident(),
+            }
+        }
+
+        fn name(&self) -> String {
+            match self {
+                Self::I24(ident) => ident.to_string(),
+                Self::Custom(custom) => custom.storage.name(),
+                _ => self.ident().to_string(),
+            }
+        }
+
+        fn path(&self) -> TokenStream {
+            match self {
+                #[cfg(feature = "half")]
+                Self::F16(ident) => quote!(::half::#ident),
+                #[cfg(feature = "fixed")]
+                Self::Fixed { ident, .. } => quote!(::fixed::types::#ident),
+                Self::Custom(custom) => custom.path.to_token_stream(),
+                _ => self.ident().into_token_stream(),
+            }
+        }
+    }
+
+    impl Ident for Modulation {
+        fn ident(&self) -> syn::Ident {
+            match self {
+                Self::Am(ident) => ident.clone(),
+                Self::Fm(ident) => ident.clone(),
             }
         }
     }
@@ -110,51 +720,319 @@ pub(crate) mod helpers {
                 None => syn::Ident::new(crate::DEFAULT_TYPE, Span::call_site()),
             }
         }
+
+        fn name(&self) -> String {
+            match self {
+                Some(item) => item.name(),
+                None => crate::DEFAULT_TYPE.to_string(),
+            }
+        }
+
+        fn path(&self) -> TokenStream {
+            match self {
+                Some(item) => item.path(),
+                None => self.ident().into_token_stream(),
+            }
+        }
     }
 
     pub(crate) trait Literal {
-        fn literal(&self, value: i32) -> proc_macro2::Literal;
+        fn literal(&self, value: i128) -> TokenTree;
+    }
+
+    /// Returns a signed integer literal of `bits` bits.
+    fn signed_literal(value: i128, bits: u32) -> proc_macro2::Literal {
+        match bits {
+            8 => proc_macro2::Literal::i8_suffixed(value as i8),
+            16 => proc_macro2::Literal::i16_suffixed(value as i16),
+            32 => proc_macro2::Literal::i32_suffixed(value as i32),
+            64 => proc_macro2::Literal::i64_suffixed(value as i64),
+            _ => proc_macro2::Literal::i128_suffixed(value),
+        }
     }
 
     impl Literal for Type {
-        fn literal(&self, value: i32) -> proc_macro2::Literal {
+        fn literal(&self, value: i128) -> TokenTree {
             match self {
-                Type::I8(_) => proc_macro2::Literal::i8_suffixed(value as i8),
-                Type::I16(_) => proc_macro2::Literal::i16_suffixed(value as i16),
-                Type::I32(_) => proc_macro2::Literal::i32_suffixed(value),
+                Type::I8(_) => signed_literal(value, i8::BITS).into(),
+                Type::I16(_) => signed_literal(value, i16::BITS).into(),
+                Type::I24(_) | Type::I32(_) => signed_literal(value, i32::BITS).into(),
+                Type::I64(_) => signed_literal(value, i64::BITS).into(),
+                Type::I128(_) => signed_literal(value, i128::BITS).into(),
+                Type::U8(_) => proc_macro2::Literal::u8_suffixed(value as u8).into(),
+                Type::U16(_) => proc_macro2::Literal::u16_suffixed(value as u16).into(),
+                Type::U32(_) => proc_macro2::Literal::u32_suffixed(value as u32).into(),
+                Type::F32(_) => proc_macro2::Literal::f32_suffixed(value as f32).into(),
+                Type::F64(_) => proc_macro2::Literal::f64_suffixed(value as f64).into(),
+                #[cfg(feature = "half")]
+                Type::F16(_) => self.float_literal(value as f64),
+                #[cfg(feature = "fixed")]
+                Type::Fixed { bits, .. } => {
+                    let path = self.path();
+                    construct(quote!(#path::from_bits), signed_literal(value, *bits))
+                }
+                Type::Custom(custom) => {
+                    construct(custom.wrap.to_token_stream(), custom.storage.literal(value))
+                }
             }
         }
     }
 
     impl<T: Literal> Literal for Option<T> {
-        fn literal(&self, value: i32) -> proc_macro2::Literal {
+        fn literal(&self, value: i128) -> TokenTree {
             match self {
                 Some(item) => item.literal(value),
-                None => proc_macro2::Literal::i16_suffixed(value as i16),
+                None => proc_macro2::Literal::i16_suffixed(value as i16).into(),
             }
         }
     }
 
     pub(crate) trait Max {
-        fn max(&self) -> i32;
+        fn max(&self) -> i128;
+
+        /// Returns the number of bits in the type.
+        fn bits(&self) -> u32;
+
+        /// Returns the value that the negative peak of the wave is scaled to.
+        fn min(&self, full_scale: FullScale) -> i128 {
+            match full_scale {
+                FullScale::Symmetric => -self.max(),
+                FullScale::Asymmetric => -self.max() - 1,
+            }
+        }
     }
 
     impl Max for Type {
-        fn max(&self) -> i32 {
+        fn max(&self) -> i128 {
+            match self {
+                Self::I8(_) => i8::MAX as i128,
+                Self::I16(_) => i16::MAX as i128,
+                Self::I24(_) => (1 << 23) - 1,
+                Self::I32(_) => i32::MAX as i128,
+                Self::I64(_) => i64::MAX as i128,
+                Self::I128(_) => i128::MAX,
+                Self::U8(_) => u8::MAX as i128,
+                Self::U16(_) => u16::MAX as i128,
+                Self::U32(_) => u32::MAX as i128,
+                // Floating point values are not scaled
+                Self::F32(_) | Self::F64(_) => 1,
+                #[cfg(feature = "half")]
+                Self::F16(_) => 1,
+                // One is scaled to `2^fraction`, or as close to it as the type can hold
+                #[cfg(feature = "fixed")]
+                Self::Fixed { bits, fraction, .. } => {
+                    let max = i128::MAX >> (i128::BITS - bits);
+                    max.min(1_i128.checked_shl(*fraction).unwrap_or(i128::MAX))
+                }
+                Self::Custom(custom) => custom.storage.max(),
+            }
+        }
+
+        fn bits(&self) -> u32 {
             match self {
-                Self::I8(_) => i8::MAX as i32,
-                Self::I16(_) => i16::MAX as i32,
-                Self::I32(_) => i32::MAX,
+                Self::I8(_) | Self::U8(_) => i8::BITS,
+                Self::I16(_) | Self::U16(_) => i16::BITS,
+                Self::I24(_) => 24,
+                Self::I32(_) | Self::U32(_) | Self::F32(_) => i32::BITS,
+                Self::I64(_) | Self::F64(_) => i64::BITS,
+                Self::I128(_) => i128::BITS,
+                #[cfg(feature = "half")]
+                Self::F16(_) => 16,
+                #[cfg(feature = "fixed")]
+                Self::Fixed { bits, .. } => *bits,
+                Self::Custom(custom) => custom.storage.bits(),
+            }
+        }
+
+        fn min(&self, full_scale: FullScale) -> i128 {
+            match (self, full_scale) {
+                // Minus one is exactly `-2^fraction` if the type can hold it
+                #[cfg(feature = "fixed")]
+                (Self::Fixed { bits, fraction, .. }, FullScale::Asymmetric) => {
+                    let min = i128::MIN >> (i128::BITS - bits);
+                    min.max(1_i128.checked_shl(*fraction).map_or(i128::MIN, |one| -one))
+                }
+                (Self::Custom(custom), full_scale) => custom.storage.min(full_scale),
+                (_, FullScale::Symmetric) => -self.max(),
+                (_, FullScale::Asymmetric) => -self.max() - 1,
             }
         }
     }
 
     impl<T: Max> Max for Option<T> {
-        fn max(&self) -> i32 {
+        fn max(&self) -> i128 {
             match self {
                 Some(item) => item.max(),
-                None => i16::MAX as i32,
+                None => i16::MAX as i128,
             }
         }
+
+        fn bits(&self) -> u32 {
+            match self {
+                Some(item) => item.bits(),
+                None => i16::BITS,
+            }
+        }
+
+        fn min(&self, full_scale: FullScale) -> i128 {
+            match self {
+                Some(item) => item.min(full_scale),
+                None => match full_scale {
+                    FullScale::Symmetric => -(i16::MAX as i128),
+                    FullScale::Asymmetric => i16::MIN as i128,
+                },
+            }
+        }
+    }
+
+    pub(crate) trait Unsigned {
+        /// Returns true for unsigned integer types, which can only hold PWM compare values and PDM
+        /// bitstreams.
+        fn is_unsigned(&self) -> bool;
+    }
+
+    impl Unsigned for Type {
+        fn is_unsigned(&self) -> bool {
+            match self {
+                Self::U8(_) | Self::U16(_) | Self::U32(_) => true,
+                Self::Custom(custom) => custom.storage.is_unsigned(),
+                _ => false,
+            }
+        }
+    }
+
+    impl<T: Unsigned> Unsigned for Option<T> {
+        fn is_unsigned(&self) -> bool {
+            match self {
+                Some(item) => item.is_unsigned(),
+                None => false,
+            }
+        }
+    }
+
+    pub(crate) trait Float {
+        /// Returns true for floating point types, whose values are not scaled to integers.
+        fn is_float(&self) -> bool;
+
+        fn float_literal(&self, value: f64) -> TokenTree;
+    }
+
+    impl Float for Type {
+        fn is_float(&self) -> bool {
+            match self {
+                Self::F32(_) | Self::F64(_) => true,
+                #[cfg(feature = "half")]
+                Self::F16(_) => true,
+                Self::Custom(custom) => custom.storage.is_float(),
+                _ => false,
+            }
+        }
+
+        fn float_literal(&self, value: f64) -> TokenTree {
+            match self {
+                Self::F32(_) => proc_macro2::Literal::f32_suffixed(value as f32).into(),
+                Self::F64(_) => proc_macro2::Literal::f64_suffixed(value).into(),
+                #[cfg(feature = "half")]
+                Self::F16(_) => {
+                    let bits = half::f16::from_f64(value).to_bits();
+                    let path = self.path();
+                    construct(
+                        quote!(#path::from_bits),
+                        proc_macro2::Literal::u16_suffixed(bits),
+                    )
+                }
+                Self::Custom(custom) => construct(
+                    custom.wrap.to_token_stream(),
+                    custom.storage.float_literal(value),
+                ),
+                _ => self.literal(value as i128),
+            }
+        }
+    }
+
+    impl<T: Float> Float for Option<T> {
+        fn is_float(&self) -> bool {
+            match self {
+                Some(item) => item.is_float(),
+                None => false,
+            }
+        }
+
+        fn float_literal(&self, value: f64) -> TokenTree {
+            match self {
+                Some(item) => item.float_literal(value),
+                None => proc_macro2::Literal::i16_suffixed(value as i16).into(),
+            }
+        }
+    }
+
+    pub(crate) trait Bytes {
+        /// Returns the integer value as bytes of the type in the given byte order.
+        fn bytes(&self, value: i128, order: ByteOrder) -> Vec<u8>;
+
+        /// Returns the floating point value as bytes of the type in the given byte order.
+        fn float_bytes(&self, value: f64, order: ByteOrder) -> Vec<u8>;
+    }
+
+    impl Bytes for Type {
+        fn bytes(&self, value: i128, order: ByteOrder) -> Vec<u8> {
+            let size = self.bits() as usize / 8;
+            match order {
+                ByteOrder::Little => value.to_le_bytes()[..size].to_vec(),
+                ByteOrder::Big => value.to_be_bytes()[16 - size..].to_vec(),
+            }
+        }
+
+        fn float_bytes(&self, value: f64, order: ByteOrder) -> Vec<u8> {
+            match (self, order) {
+                (Self::F32(_), ByteOrder::Little) => (value as f32).to_le_bytes().to_vec(),
+                (Self::F32(_), ByteOrder::Big) => (value as f32).to_be_bytes().to_vec(),
+                (Self::F64(_), ByteOrder::Little) => value.to_le_bytes().to_vec(),
+                (Self::F64(_), ByteOrder::Big) => value.to_be_bytes().to_vec(),
+                #[cfg(feature = "half")]
+                (Self::F16(_), _) => self.bytes(half::f16::from_f64(value).to_bits().into(), order),
+                (Self::Custom(custom), _) => custom.storage.float_bytes(value, order),
+                _ => self.bytes(value as i128, order),
+            }
+        }
+    }
+
+    impl<T: Bytes> Bytes for Option<T> {
+        fn bytes(&self, value: i128, order: ByteOrder) -> Vec<u8> {
+            match (self, order) {
+                (Some(item), _) => item.bytes(value, order),
+                (None, ByteOrder::Little) => (value as i16).to_le_bytes().to_vec(),
+                (None, ByteOrder::Big) => (value as i16).to_be_bytes().to_vec(),
+            }
+        }
+
+        fn float_bytes(&self, value: f64, order: ByteOrder) -> Vec<u8> {
+            match self {
+                Some(item) => item.float_bytes(value, order),
+                None => self.bytes(value as i128, order),
+            }
+        }
+    }
+
+    pub(crate) trait Fraction {
+        /// Returns the number of fraction bits of fixed point types.
+        fn fraction(&self) -> Option<u32>;
+    }
+
+    impl Fraction for Type {
+        fn fraction(&self) -> Option<u32> {
+            match self {
+                #[cfg(feature = "fixed")]
+                Self::Fixed { fraction, .. } => Some(*fraction),
+                Self::Custom(custom) => custom.storage.fraction(),
+                _ => None,
+            }
+        }
+    }
+
+    impl<T: Fraction> Fraction for Option<T> {
+        fn fraction(&self) -> Option<u32> {
+            self.as_ref().and_then(|item| item.fraction())
+        }
     }
 }
@@ -0,0 +1,231 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use crate::items::Attrs;
+use crate::types::{Number, Type, parse_int};
+use crate::{check_signed, set_once};
+use proc_macro2::Span;
+use std::f64::consts::PI;
+use std::num::NonZeroUsize;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitInt, Result, Token};
+
+#[derive(Clone, Copy)]
+pub(crate) enum WindowKind {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+    BlackmanHarris,
+    FlatTop,
+    Kaiser,
+}
+
+impl Parse for WindowKind {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "rectangular" => Ok(WindowKind::Rectangular),
+            "hann" => Ok(WindowKind::Hann),
+            "hamming" => Ok(WindowKind::Hamming),
+            "blackman" => Ok(WindowKind::Blackman),
+            "blackman_harris" => Ok(WindowKind::BlackmanHarris),
+            "flat_top" => Ok(WindowKind::FlatTop),
+            "kaiser" => Ok(WindowKind::Kaiser),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid window, must be one of `rectangular`, `hann`, `hamming`, `blackman`, `blackman_harris`, `flat_top` and `kaiser`",
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Symmetry {
+    Symmetric,
+    Periodic,
+}
+
+impl Parse for Symmetry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let value: Ident = input.parse()?;
+        match value.to_string().as_ref() {
+            "symmetric" => Ok(Symmetry::Symmetric),
+            "periodic" => Ok(Symmetry::Periodic),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid value for `symmetry`, must be one of `symmetric` and `periodic`",
+            )),
+        }
+    }
+}
+
+enum WindowAttrInput {
+    Kind(Ident, WindowKind),
+    Len(Ident, LitInt),
+    Beta(Ident, Number),
+    Symmetry(Ident, Symmetry),
+    Type(Token![type], Type),
+}
+
+impl Parse for WindowAttrInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![type]) {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            return Ok(WindowAttrInput::Type(name, input.parse()?));
+        }
+        let name: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        match name.to_string().as_ref() {
+            "kind" => Ok(WindowAttrInput::Kind(name, input.parse()?)),
+            "len" => Ok(WindowAttrInput::Len(name.clone(), parse_int(input, &name)?)),
+            "beta" => Ok(WindowAttrInput::Beta(name, input.parse()?)),
+            "symmetry" => Ok(WindowAttrInput::Symmetry(name, input.parse()?)),
+            _ => Err(Error::new(
+                name.span(),
+                "invalid identifier, must be one of `kind`, `len`, `beta`, `symmetry` and `type`",
+            )),
+        }
+    }
+}
+
+pub(crate) struct WindowAttrs {
+    pub kind: WindowKind,
+    pub len: LitInt,
+    pub beta: Option<Number>,
+    pub symmetry: Option<Symmetry>,
+    pub ty: Option<Type>,
+}
+
+/// Checks that `beta` is given if and only if `kind` is Kaiser window.
+pub(crate) fn check_beta(kind: WindowKind, beta: &Option<Number>, span: Span) -> Result<()> {
+    match (kind, beta) {
+        (WindowKind::Kaiser, None) => Err(Error::new(
+            span,
+            "`beta` must be defined for `kaiser` window",
+        )),
+        (WindowKind::Kaiser, Some(_)) | (_, None) => Ok(()),
+        (_, Some(beta)) => Err(Error::new_spanned(
+            beta,
+            "`beta` can be used only with `kaiser` window",
+        )),
+    }
+}
+
+impl Parse for WindowAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = Punctuated::<WindowAttrInput, Token![,]>::parse_terminated(input)?;
+        let mut kind = None;
+        let mut len = None;
+        let mut beta = None;
+        let mut symmetry = None;
+        let mut ty = None;
+        for attr in attrs {
+            match attr {
+                WindowAttrInput::Kind(name, attr_value) => set_once(&mut kind, &name, attr_value)?,
+                WindowAttrInput::Len(name, attr_value) => {
+                    set_once(&mut len, &name, attr_value.clone())?;
+                    let _value: NonZeroUsize = attr_value.base10_parse()?;
+                }
+                WindowAttrInput::Beta(name, attr_value) => set_once(&mut beta, &name, attr_value)?,
+                WindowAttrInput::Symmetry(name, attr_value) => {
+                    set_once(&mut symmetry, &name, attr_value)?
+                }
+                WindowAttrInput::Type(name, attr_value) => set_once(&mut ty, &name, attr_value)?,
+            }
+        }
+        let Some(kind) = kind else {
+            return Err(Error::new(input.span(), "`kind` must be defined"));
+        };
+        let Some(len) = len else {
+            return Err(Error::new(input.span(), "`len` must be defined"));
+        };
+        check_signed(&ty)?;
+        check_beta(kind, &beta, input.span())?;
+        Ok(WindowAttrs {
+            kind,
+            len,
+            beta,
+            symmetry,
+            ty,
+        })
+    }
+}
+
+impl Attrs for WindowAttrs {
+    const NAME: &'static str = "window";
+}
+
+/// Zeroth order modified Bessel function of the first kind.
+fn bessel_i0(x: f64) -> f64 {
+    let mut sum = 1_f64;
+    let mut term = 1_f64;
+    let mut k = 1_f64;
+    while term > sum * f64::EPSILON {
+        term *= (x / (2_f64 * k)).powi(2);
+        sum += term;
+        k += 1_f64;
+    }
+    sum
+}
+
+/// Sums cosine terms with the given coefficients and alternating signs.
+fn cosine_sum(coefficients: &[f64], phase: f64) -> f64 {
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(k, a)| {
+            let sign = if k % 2 == 0 { 1_f64 } else { -1_f64 };
+            sign * a * (k as f64 * phase).cos()
+        })
+        .sum()
+}
+
+/// Returns a window of `len` values which peak at (approximately) one.
+pub(crate) fn get_window(
+    kind: WindowKind,
+    beta: Option<f64>,
+    symmetry: Symmetry,
+    len: usize,
+) -> Vec<f64> {
+    let period = match symmetry {
+        Symmetry::Symmetric => len - 1,
+        Symmetry::Periodic => len,
+    };
+    if period == 0 {
+        return vec![1_f64; len];
+    }
+    (0..len)
+        .map(|n| {
+            let phase = 2_f64 * PI * n as f64 / period as f64;
+            match kind {
+                WindowKind::Rectangular => 1_f64,
+                WindowKind::Hann => cosine_sum(&[0.5, 0.5], phase),
+                WindowKind::Hamming => cosine_sum(&[0.54, 0.46], phase),
+                WindowKind::Blackman => cosine_sum(&[0.42, 0.5, 0.08], phase),
+                WindowKind::BlackmanHarris => {
+                    cosine_sum(&[0.35875, 0.48829, 0.14128, 0.01168], phase)
+                }
+                WindowKind::FlatTop => cosine_sum(
+                    &[
+                        0.21557895,
+                        0.41663158,
+                        0.277263158,
+                        0.083578947,
+                        0.006947368,
+                    ],
+                    phase,
+                ),
+                WindowKind::Kaiser => {
+                    let beta = beta.unwrap();
+                    let ratio = 2_f64 * n as f64 / period as f64 - 1_f64;
+                    bessel_i0(beta * (1_f64 - ratio * ratio).max(0_f64).sqrt()) / bessel_i0(beta)
+                }
+            }
+        })
+        .collect()
+}
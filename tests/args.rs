@@ -0,0 +1,135 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::sine_wave;
+use sine_macro_core::Error;
+use sine_macro_core::args::{ARGUMENTS, MACRO_ONLY, WaveArgs};
+use std::fs;
+
+/// Returns the first error that `sine_wave!` gives in the compile test called `name`, and the
+/// arguments of the macro.
+fn macro_error(name: &str) -> (String, String) {
+    let source = fs::read_to_string(format!("tests/fail/{}.rs", name)).unwrap();
+    let start = source.find("sine_wave!(").unwrap() + "sine_wave!(".len();
+    let end = start + source[start..].find(");").unwrap();
+    let stderr = fs::read_to_string(format!("tests/fail/{}.stderr", name)).unwrap();
+    let error = stderr.lines().next().unwrap().trim_start_matches("error: ");
+    (source[start..end].to_string(), error.to_string())
+}
+
+#[test]
+fn test_parse() {
+    let args: WaveArgs = "frequency: 441, rate: 44_100, repeats: 2,".parse().unwrap();
+    assert_eq!(
+        args.spec.generate_int(16).unwrap(),
+        sine_wave!(frequency: 441, rate: 44_100, repeats: 2).map(i64::from)
+    );
+    let args: WaveArgs = "type: i8, frequency: 10, rate: 100, rounding: nearest, dither: 7"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        args.ty.literals(&args.spec).unwrap(),
+        sine_wave!(frequency: 10, rate: 100, type: i8, rounding: nearest, dither: 7)
+            .map(|value| value.to_string())
+    );
+    let args: WaveArgs = "frequency: 10, rate: 100, modulation: fm, modulator: 5, deviation: 5"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        args.spec.generate_int(16).unwrap(),
+        sine_wave!(frequency: 10, rate: 100, modulation: fm, modulator: 5, deviation: 5)
+            .map(i64::from)
+    );
+}
+
+#[test]
+fn test_same_errors() {
+    for name in [
+        "depth_with_fm",
+        "deviation_with_am",
+        "float_rounding",
+        "fm_without_deviation",
+        "invalid_depth",
+        "invalid_deviation",
+        "invalid_frequency",
+        "invalid_full_scale",
+        "invalid_modulation",
+        "invalid_modulator",
+        "invalid_name",
+        "invalid_type",
+        "invalid_rate",
+        "invalid_rounding",
+        "invalid_values",
+        "len_and_repeats",
+        "modulation_without_modulator",
+        "modulator_without_modulation",
+        "negative_frequency",
+        "negative_len",
+        "negative_rate",
+        "negative_repeats",
+        "negative_skip",
+        "no_arguments",
+        "repeats_and_len",
+        "twice_dither",
+        "twice_frequency",
+        "twice_full_scale",
+        "twice_len",
+        "twice_modulation",
+        "twice_rate",
+        "twice_repeats",
+        "twice_rounding",
+        "twice_skip",
+        "twice_type",
+        "zero_deviation",
+        "zero_frequency",
+        "zero_len",
+        "zero_rate",
+        "zero_repeats",
+    ] {
+        let (args, expected) = macro_error(name);
+        let error = args
+            .parse::<WaveArgs>()
+            .and_then(|args| args.ty.literals(&args.spec))
+            .unwrap_err();
+        assert_eq!(error.to_string(), expected, "{}", name);
+    }
+}
+
+#[test]
+fn test_macro_only_arguments() {
+    let error = "frequency: 440, quadrature: pairs"
+        .parse::<WaveArgs>()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "`quadrature` is supported only by `sine_wave!`"
+    );
+    for ty in ["u16", "I1F15"] {
+        let error = format!("frequency: 440, type: {}", ty)
+            .parse::<WaveArgs>()
+            .unwrap_err();
+        assert_eq!(error, Error::MacroOnly(format!("type: {}", ty)));
+    }
+}
+
+#[test]
+fn test_argument_names() {
+    for name in ARGUMENTS {
+        let error = format!("frequency: 440, {}: invalid", name)
+            .parse::<WaveArgs>()
+            .unwrap_err();
+        assert_ne!(error, Error::InvalidIdentifier, "{}", name);
+        assert_eq!(
+            error == Error::MacroOnly(name.to_string()),
+            MACRO_ONLY.contains(name),
+            "{}",
+            name
+        );
+    }
+    let error = "frequency: 440, volume: 11"
+        .parse::<WaveArgs>()
+        .unwrap_err();
+    assert_eq!(error, Error::InvalidIdentifier);
+}
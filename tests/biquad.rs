@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::biquad;
+
+biquad! {
+    static STATIC_LOWPASS = biquad(kind: lowpass, rate: 48_000, frequency: 1_000);
+}
+
+biquad! {
+    pub const EXPORTED_LOWPASS = biquad(kind: lowpass, rate: 48_000, frequency: 1_000);
+}
+
+const LOWPASS_48000_1000: [i16; 5] = [64, 128, 64, -29743, 13615];
+
+fn assert_close<const N: usize>(values: [f64; N], expected: [f64; N]) {
+    for (value, expected) in values.iter().zip(expected) {
+        assert!(
+            (value - expected).abs() < 1e-12,
+            "{} != {}",
+            value,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_lowpass() {
+    let lowpass = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000);
+    assert_eq!(lowpass, LOWPASS_48000_1000);
+    let lowpass = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, q: 0.7071067811865476, fraction: 14, type: i16);
+    assert_eq!(lowpass, LOWPASS_48000_1000);
+    assert_eq!(STATIC_LOWPASS, LOWPASS_48000_1000);
+    assert_eq!(EXPORTED_LOWPASS, LOWPASS_48000_1000);
+}
+
+#[test]
+fn test_highpass() {
+    let highpass = biquad!(kind: highpass, rate: 8_000, frequency: 1_000);
+    assert_eq!(highpass, [9323, -18646, 9323, -15447, 5461]);
+}
+
+#[test]
+fn test_notch() {
+    let notch = biquad!(kind: notch, rate: 8_000, frequency: 50, q: 10);
+    assert_eq!(notch, [16352, -32679, 16352, -32679, 16320]);
+}
+
+#[test]
+fn test_peaking() {
+    let peaking = biquad!(kind: peaking, rate: 48_000, frequency: 1_000, q: 1, gain: 6, type: i8);
+    assert_eq!(peaking, [67, -121, 56, -121, 58]);
+    let peaking =
+        biquad!(kind: peaking, rate: 48_000, frequency: 3_000, q: 2.5, gain: -6, type: f64);
+    assert_close(
+        peaking,
+        [
+            0.9513341881726256,
+            -1.667485621713143,
+            0.853539242869394,
+            -1.667485621713143,
+            0.8048734310420194,
+        ],
+    );
+}
+
+#[test]
+fn test_shelves() {
+    let lowshelf = biquad!(kind: lowshelf, rate: 48_000, frequency: 200, gain: 6, type: f64);
+    assert_close(
+        lowshelf,
+        [
+            1.0064455778511419,
+            -1.9686123523200318,
+            0.963120058272841,
+            -1.9688501073857254,
+            0.9693278810582894,
+        ],
+    );
+    // Gain at DC of a low shelf is `gain` and at Nyquist of a high shelf is `gain` too
+    let [b0, b1, b2, a1, a2] = lowshelf;
+    let dc_gain = (b0 + b1 + b2) / (1_f64 + a1 + a2);
+    assert!((20_f64 * dc_gain.log10() - 6_f64).abs() < 1e-9);
+    let [b0, b1, b2, a1, a2] =
+        biquad!(kind: highshelf, rate: 48_000, frequency: 8_000, gain: -3.5, type: f64);
+    let nyquist_gain = (b0 - b1 + b2) / (1_f64 - a1 + a2);
+    assert!((20_f64 * nyquist_gain.log10() + 3.5_f64).abs() < 1e-9);
+}
+
+#[test]
+fn test_float_types() {
+    let lowpass = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: f32);
+    for (value, expected) in lowpass.iter().zip(LOWPASS_48000_1000) {
+        assert!((value * 16384_f32 - expected as f32).abs() <= 0.5);
+    }
+}
+
+#[test]
+fn test_wide_types() {
+    let reference = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: f64);
+    let lowpass = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: i64);
+    assert_close(
+        lowpass.map(|value| value as f64 / (1_i64 << 62) as f64),
+        reference,
+    );
+    let lowpass = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: i128);
+    assert_close(
+        lowpass.map(|value| value as f64 / (1_i128 << 126) as f64),
+        reference,
+    );
+}
@@ -23,6 +23,7 @@ fn test_compile_zero_values() {
     t.compile_fail("tests/fail/zero_rate.rs");
     t.compile_fail("tests/fail/zero_len.rs");
     t.compile_fail("tests/fail/zero_repeats.rs");
+    t.compile_fail("tests/fail/zero_deviation.rs");
 }
 
 #[test]
@@ -33,6 +34,7 @@ fn test_compile_negative_values() {
     t.compile_fail("tests/fail/negative_len.rs");
     t.compile_fail("tests/fail/negative_repeats.rs");
     t.compile_fail("tests/fail/negative_skip.rs");
+    t.compile_fail("tests/fail/negative_dither.rs");
 }
 
 #[test]
@@ -50,9 +52,25 @@ fn test_compile_invalid_values() {
     t.compile_fail("tests/fail/invalid_frequency.rs");
     t.compile_fail("tests/fail/invalid_rate.rs");
     t.compile_fail("tests/fail/invalid_type.rs");
+    t.compile_fail("tests/fail/invalid_storage.rs");
+    t.compile_fail("tests/fail/invalid_fixed.rs");
     t.compile_fail("tests/fail/invalid_values.rs");
     t.compile_fail("tests/fail/invalid_values2.rs");
     t.compile_fail("tests/fail/invalid_values3.rs");
+    t.compile_fail("tests/fail/invalid_modulation.rs");
+    t.compile_fail("tests/fail/invalid_modulator.rs");
+    t.compile_fail("tests/fail/invalid_depth.rs");
+    t.compile_fail("tests/fail/invalid_deviation.rs");
+    t.compile_fail("tests/fail/invalid_rounding.rs");
+    t.compile_fail("tests/fail/invalid_full_scale.rs");
+    t.compile_fail("tests/fail/invalid_quadrature.rs");
+    t.compile_fail("tests/fail/invalid_shape.rs");
+    t.compile_fail("tests/fail/invalid_layout.rs");
+    t.compile_fail("tests/fail/invalid_bit_order.rs");
+    t.compile_fail("tests/fail/invalid_bytes.rs");
+    t.compile_fail("tests/fail/invalid_justify.rs");
+    t.compile_fail("tests/fail/justify_without_i24.rs");
+    t.compile_fail("tests/fail/float_rounding.rs");
 }
 
 #[test]
@@ -64,6 +82,16 @@ fn test_compile_defined_twice() {
     t.compile_fail("tests/fail/twice_repeats.rs");
     t.compile_fail("tests/fail/twice_skip.rs");
     t.compile_fail("tests/fail/twice_type.rs");
+    t.compile_fail("tests/fail/twice_modulation.rs");
+    t.compile_fail("tests/fail/twice_rounding.rs");
+    t.compile_fail("tests/fail/twice_dither.rs");
+    t.compile_fail("tests/fail/twice_full_scale.rs");
+    t.compile_fail("tests/fail/twice_dead_time.rs");
+    t.compile_fail("tests/fail/twice_pdm.rs");
+    t.compile_fail("tests/fail/twice_bytes.rs");
+    t.compile_fail("tests/fail/twice_justify.rs");
+    t.compile_fail("tests/fail/twice_storage.rs");
+    t.compile_fail("tests/fail/twice_wrap.rs");
 }
 
 #[test]
@@ -72,3 +100,145 @@ fn test_compile_both_repeats_and_len() {
     t.compile_fail("tests/fail/len_and_repeats.rs");
     t.compile_fail("tests/fail/repeats_and_len.rs");
 }
+
+#[test]
+fn test_compile_modulation_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/modulation_without_modulator.rs");
+    t.compile_fail("tests/fail/modulator_without_modulation.rs");
+    t.compile_fail("tests/fail/quadrature_with_modulation.rs");
+    t.compile_fail("tests/fail/depth_with_fm.rs");
+    t.compile_fail("tests/fail/deviation_with_am.rs");
+    t.compile_fail("tests/fail/fm_without_deviation.rs");
+}
+
+#[test]
+fn test_compile_phase_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/phases_too_few.rs");
+    t.compile_fail("tests/fail/phases_with_quadrature.rs");
+    t.compile_fail("tests/fail/shape_without_phases.rs");
+    t.compile_fail("tests/fail/shape_with_two_phases.rs");
+}
+
+#[test]
+fn test_compile_pwm_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/pwm_top_overflow.rs");
+    t.compile_fail("tests/fail/pwm_top_with_float.rs");
+    t.compile_fail("tests/fail/pwm_top_with_full_scale.rs");
+    t.compile_fail("tests/fail/unsigned_without_pwm_top.rs");
+    t.compile_fail("tests/fail/dead_time_too_large.rs");
+    t.compile_fail("tests/fail/dead_time_without_pwm_top.rs");
+}
+
+#[test]
+fn test_compile_pdm_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/pdm_signed_type.rs");
+    t.compile_fail("tests/fail/pdm_with_pwm_top.rs");
+    t.compile_fail("tests/fail/pdm_zero_oversampling.rs");
+    t.compile_fail("tests/fail/bit_order_without_pdm.rs");
+}
+
+#[test]
+fn test_compile_noise() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/noise_no_seed.rs");
+    t.compile_fail("tests/fail/noise_no_len.rs");
+    t.compile_fail("tests/fail/noise_zero_seed.rs");
+    t.compile_fail("tests/fail/noise_invalid_kind.rs");
+    t.compile_fail("tests/fail/noise_invalid_name.rs");
+    t.compile_fail("tests/fail/noise_twice_seed.rs");
+    t.compile_fail("tests/fail/noise_bad_name.rs");
+}
+
+#[test]
+fn test_compile_window() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/window_no_kind.rs");
+    t.compile_fail("tests/fail/window_no_len.rs");
+    t.compile_fail("tests/fail/window_invalid_kind.rs");
+    t.compile_fail("tests/fail/window_invalid_symmetry.rs");
+    t.compile_fail("tests/fail/window_kaiser_without_beta.rs");
+    t.compile_fail("tests/fail/window_beta_without_kaiser.rs");
+    t.compile_fail("tests/fail/window_twice_kind.rs");
+}
+
+#[test]
+fn test_compile_fir() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/fir_insufficient_taps.rs");
+    t.compile_fail("tests/fail/fir_even_taps.rs");
+    t.compile_fail("tests/fail/fir_cutoff_too_high.rs");
+    t.compile_fail("tests/fail/fir_low_above_high.rs");
+    t.compile_fail("tests/fail/fir_no_cutoff.rs");
+    t.compile_fail("tests/fail/fir_cutoff_with_bandpass.rs");
+    t.compile_fail("tests/fail/fir_twice_taps.rs");
+    t.compile_fail("tests/fail/fir_unsigned.rs");
+    t.compile_fail("tests/fail/fir_coefficients_too_large.rs");
+    t.compile_fail("tests/fail/fir_fraction_too_large.rs");
+}
+
+#[test]
+fn test_compile_biquad() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/biquad_no_gain.rs");
+    t.compile_fail("tests/fail/biquad_gain_with_lowpass.rs");
+    t.compile_fail("tests/fail/biquad_frequency_too_high.rs");
+    t.compile_fail("tests/fail/biquad_zero_q.rs");
+    t.compile_fail("tests/fail/biquad_fraction_overflow.rs");
+    t.compile_fail("tests/fail/biquad_fraction_too_large.rs");
+    t.compile_fail("tests/fail/biquad_fraction_with_float.rs");
+    t.compile_fail("tests/fail/biquad_invalid_kind.rs");
+}
+
+#[test]
+fn test_compile_goertzel() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/goertzel_same_bin.rs");
+    t.compile_fail("tests/fail/goertzel_bin_zero.rs");
+    t.compile_fail("tests/fail/goertzel_no_block.rs");
+    t.compile_fail("tests/fail/goertzel_empty_frequencies.rs");
+    t.compile_fail("tests/fail/goertzel_invalid_frequency.rs");
+    t.compile_fail("tests/fail/goertzel_fraction_overflow.rs");
+}
+
+#[test]
+fn test_compile_twiddles() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/twiddles_not_power_of_two.rs");
+    t.compile_fail("tests/fail/twiddles_too_small.rs");
+    t.compile_fail("tests/fail/twiddles_invalid_layout.rs");
+    t.compile_fail("tests/fail/twiddles_no_size.rs");
+    t.compile_fail("tests/fail/twiddles_twice_size.rs");
+}
+
+#[test]
+fn test_compile_custom_type_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/storage_with_builtin_type.rs");
+    t.compile_fail("tests/fail/storage_without_type.rs");
+    t.compile_fail("tests/fail/wrap_without_storage.rs");
+}
+
+#[test]
+fn test_compile_constant_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/constant_with_modulation.rs");
+    t.compile_fail("tests/fail/constant_with_unsigned.rs");
+    t.compile_fail("tests/fail/constant_len.rs");
+    t.compile_fail("tests/fail/fir_constant_rate.rs");
+    t.compile_fail("tests/fail/divide_by_zero.rs");
+    t.compile_fail("tests/fail/overflowing_expression.rs");
+    t.compile_fail("tests/fail/negative_expression.rs");
+    t.compile_fail("tests/fail/too_large_expression.rs");
+}
+
+#[test]
+#[cfg(feature = "fixed")]
+fn test_compile_fixed_arguments() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/fail/fixed_with_fraction.rs");
+    t.compile_fail("tests/fail/fixed_with_pwm_top.rs");
+}
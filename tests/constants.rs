@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::{biquad, fir, goertzel, noise, sine_wave, twiddles, window};
+
+const SAMPLE_RATE: u32 = 48_000;
+const TONE: u32 = 1_000;
+
+mod config {
+    pub const RATE: u32 = 16_000;
+}
+
+sine_wave! {
+    const CONST_WAVE = sine_wave(frequency: TONE, rate: SAMPLE_RATE);
+}
+
+sine_wave! {
+    static STATIC_WAVE = sine_wave(frequency: 440, rate: config::RATE, type: f32, repeats: 2);
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(
+        sine_wave!(frequency: 2 * 500, rate: 48 * 1_000),
+        sine_wave!(frequency: 1_000, rate: 48_000)
+    );
+    assert_eq!(
+        sine_wave!(frequency: (1_000 + 200) / 3, rate: 8_000, len: 10 - 2 % 2),
+        sine_wave!(frequency: 400, rate: 8_000, len: 10)
+    );
+    assert_eq!(noise!(seed: 40 + 2, len: 4 * 4), noise!(seed: 42, len: 16));
+    assert_eq!(
+        window!(kind: hann, len: 2 * 8),
+        window!(kind: hann, len: 16)
+    );
+    assert_eq!(
+        fir!(kind: lowpass, rate: 16 * 1_000, cutoff: 1_000, taps: 30 + 1),
+        fir!(kind: lowpass, rate: 16_000, cutoff: 1_000, taps: 31)
+    );
+    assert_eq!(
+        biquad!(kind: lowpass, rate: 48_000, frequency: 2 * 500, fraction: 16 - 2),
+        biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, fraction: 14)
+    );
+    assert_eq!(
+        goertzel!(rate: 8_000, block: 205, frequencies: [697, 700 + 70]),
+        goertzel!(rate: 8_000, block: 205, frequencies: [697, 770])
+    );
+    assert_eq!(twiddles!(size: 2 * 8), twiddles!(size: 16));
+}
+
+#[test]
+fn test_constants() {
+    let wave = sine_wave!(frequency: TONE, rate: SAMPLE_RATE);
+    let expected = sine_wave!(frequency: 1_000, rate: 48_000);
+    assert_eq!(wave, expected);
+    assert_eq!(CONST_WAVE, expected);
+    let wave = sine_wave!(frequency: 440, rate: config::RATE, type: f32, repeats: 2);
+    let expected = sine_wave!(frequency: 440, rate: 16_000, type: f32, repeats: 2);
+    assert_eq!(wave, expected);
+    assert_eq!(STATIC_WAVE, wave);
+}
+
+#[test]
+fn test_constant_expressions() {
+    let wave = sine_wave!(frequency: TONE / 2, rate: SAMPLE_RATE / 2, len: 100, skip: 12);
+    let expected = sine_wave!(frequency: 500, rate: 24_000, len: 100, skip: 12);
+    assert_eq!(wave, expected);
+    let wave = sine_wave!(frequency: TONE, type: i8, rounding: nearest, full_scale: asymmetric);
+    let expected =
+        sine_wave!(frequency: 1_000, type: i8, rounding: nearest, full_scale: asymmetric);
+    assert_eq!(wave, expected);
+}
+
+#[test]
+fn test_constant_i128_asymmetric() {
+    const RATE: u32 = 400;
+    let wave = sine_wave!(frequency: 100, rate: RATE, type: i128, full_scale: asymmetric);
+    assert_eq!(wave, [0, i128::MAX, 0, i128::MIN]);
+    assert_eq!(
+        wave,
+        sine_wave!(frequency: 100, rate: 400, type: i128, full_scale: asymmetric)
+    );
+}
+
+#[test]
+fn test_constant_same_values() {
+    const RATE: u32 = 44_100;
+    assert_eq!(
+        sine_wave!(frequency: 441, rate: RATE, type: f64),
+        sine_wave!(frequency: 441, rate: 44_100, type: f64)
+    );
+    assert_eq!(
+        sine_wave!(frequency: 7, rate: RATE, type: i64, rounding: nearest, skip: 3),
+        sine_wave!(frequency: 7, rate: 44_100, type: i64, rounding: nearest, skip: 3)
+    );
+    assert_eq!(
+        sine_wave!(frequency: 997, rate: RATE, type: i32, rounding: ceil),
+        sine_wave!(frequency: 997, rate: 44_100, type: i32, rounding: ceil)
+    );
+}
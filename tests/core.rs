@@ -0,0 +1,312 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::sine_wave;
+use sine_macro_core::codegen::{Generator, Item, SampleType};
+use sine_macro_core::{
+    Error, FullScale, Modulation, Rounding, SplitMix64, WaveSpec, cosine_wave, lagging_sine_wave,
+    quantize,
+};
+
+fn to_i16(values: Vec<i64>) -> Vec<i16> {
+    values.into_iter().map(|value| value as i16).collect()
+}
+
+fn to_i8(values: Vec<i64>) -> Vec<i8> {
+    values.into_iter().map(|value| value as i8).collect()
+}
+
+#[test]
+fn test_generate_int() {
+    let spec = WaveSpec::new(441).with_rate(44100);
+    assert_eq!(
+        to_i16(spec.generate_int(16).unwrap()),
+        sine_wave!(frequency: 441, rate: 44100)
+    );
+    let spec = WaveSpec::new(441);
+    assert_eq!(
+        to_i16(spec.generate_int(16).unwrap()),
+        sine_wave!(frequency: 441)
+    );
+    let spec = WaveSpec::new(441).with_rate(44100).with_len(10);
+    assert_eq!(
+        to_i16(spec.generate_int(16).unwrap()),
+        sine_wave!(frequency: 441, rate: 44100, len: 10)
+    );
+    let spec = WaveSpec::new(10).with_rate(100).with_repeats(2);
+    assert_eq!(
+        to_i16(spec.generate_int(16).unwrap()),
+        sine_wave!(frequency: 10, rate: 100, repeats: 2)
+    );
+    let spec = WaveSpec::new(10).with_rate(100).with_skip(15);
+    assert_eq!(
+        to_i16(spec.generate_int(16).unwrap()),
+        sine_wave!(frequency: 10, rate: 100, skip: 15)
+    );
+    let spec = WaveSpec::new(1_000).with_rate(48_000);
+    assert_eq!(
+        spec.generate_int(32).unwrap(),
+        sine_wave!(frequency: 1_000, rate: 48_000, type: i32).map(i64::from)
+    );
+    assert_eq!(
+        spec.generate_int(64).unwrap(),
+        sine_wave!(frequency: 1_000, rate: 48_000, type: i64)
+    );
+}
+
+#[test]
+fn test_generate_int_options() {
+    let spec = WaveSpec::new(10).with_rate(100);
+    for (rounding, expected) in [
+        (
+            Rounding::Truncate,
+            sine_wave!(frequency: 10, rate: 100, type: i8),
+        ),
+        (
+            Rounding::Nearest,
+            sine_wave!(frequency: 10, rate: 100, type: i8, rounding: nearest),
+        ),
+        (
+            Rounding::Floor,
+            sine_wave!(frequency: 10, rate: 100, type: i8, rounding: floor),
+        ),
+        (
+            Rounding::Ceil,
+            sine_wave!(frequency: 10, rate: 100, type: i8, rounding: ceil),
+        ),
+    ] {
+        let spec = spec.clone().with_rounding(rounding);
+        assert_eq!(to_i8(spec.generate_int(8).unwrap()), expected);
+    }
+    let dithered = spec.clone().with_rounding(Rounding::Nearest).with_dither(7);
+    assert_eq!(
+        to_i8(dithered.generate_int(8).unwrap()),
+        sine_wave!(frequency: 10, rate: 100, type: i8, rounding: nearest, dither: 7)
+    );
+    let asymmetric = spec.with_full_scale(FullScale::Asymmetric);
+    assert_eq!(
+        to_i8(asymmetric.generate_int(8).unwrap()),
+        sine_wave!(frequency: 10, rate: 100, type: i8, full_scale: asymmetric)
+    );
+    let spec = WaveSpec::new(100).with_rate(800);
+    assert_eq!(
+        spec.generate_int(24).unwrap(),
+        sine_wave!(frequency: 100, rate: 800, type: i24).map(i64::from)
+    );
+}
+
+#[test]
+fn test_quantize_i128() {
+    let values = quantize(
+        &[0_f64, 1_f64, -1_f64],
+        i128::MIN,
+        i128::MAX,
+        Rounding::Truncate,
+        None,
+    );
+    assert_eq!(values, [0, i128::MAX, i128::MIN]);
+    let wave = sine_macro_core::sine_wave(100, 400);
+    assert_eq!(
+        quantize(&wave, i128::MIN, i128::MAX, Rounding::Nearest, None),
+        sine_wave!(frequency: 100, rate: 400, type: i128, rounding: nearest, full_scale: asymmetric)
+    );
+}
+
+#[test]
+fn test_quadrature_dither() {
+    let (sine, cosine) = sine_wave!(
+        frequency: 100,
+        rate: 700,
+        type: i8,
+        rounding: nearest,
+        dither: 42,
+        quadrature: tables
+    );
+    let wave = sine_macro_core::sine_wave(100, 700);
+    let values = quantize(&wave, -127, 127, Rounding::Nearest, Some(42));
+    assert_eq!(values, sine.map(i128::from));
+    // The cosine is dithered with a seed of its own
+    let seed = SplitMix64::new(42).next_u64();
+    let values = quantize(
+        &cosine_wave(100, 700),
+        -127,
+        127,
+        Rounding::Nearest,
+        Some(seed),
+    );
+    assert_eq!(values, cosine.map(i128::from));
+}
+
+#[test]
+fn test_generate_modulated() {
+    let am = WaveSpec::new(10)
+        .with_rate(100)
+        .with_modulation(Modulation::Am {
+            modulator: 5,
+            depth: 50,
+        });
+    assert_eq!(
+        to_i16(am.generate_int(16).unwrap()),
+        sine_wave!(frequency: 10, rate: 100, modulation: am, modulator: 5, depth: 50)
+    );
+    let fm = WaveSpec::new(440)
+        .with_rate(48_000)
+        .with_modulation(Modulation::Fm {
+            modulator: 6,
+            deviation: 40,
+        })
+        .with_repeats(2);
+    assert_eq!(
+        to_i16(fm.generate_int(16).unwrap()),
+        sine_wave!(frequency: 440, rate: 48_000, modulation: fm, modulator: 6, deviation: 40, repeats: 2)
+    );
+}
+
+#[test]
+fn test_generate() {
+    let spec = WaveSpec::new(441).with_rate(44100).with_len(150);
+    assert_eq!(
+        spec.generate().unwrap(),
+        sine_wave!(frequency: 441, rate: 44100, len: 150, type: f64)
+    );
+    assert_eq!(spec.count(), Ok(150));
+    let spec = WaveSpec::new(100).with_rate(700).with_skip(3);
+    assert_eq!(
+        spec.generate().unwrap(),
+        sine_wave!(frequency: 100, rate: 700, skip: 3, type: f64)
+    );
+    assert_eq!(spec.count(), Ok(7));
+    assert_eq!(spec.effective_frequency(), Ok(100_f64));
+    let spec = WaveSpec::new(440).with_rate(48_000);
+    assert_eq!(spec.effective_frequency(), Ok(48_000_f64 / 109_f64));
+}
+
+#[test]
+fn test_lagging_sine_wave() {
+    let wave = sine_macro_core::sine_wave(440, 44_100);
+    assert_eq!(lagging_sine_wave(440, 44_100, 0, 3), wave);
+    assert_eq!(lagging_sine_wave(440, 44_100, 3, 3), wave);
+    // A quarter of a period behind is the negated cosine, exact at the quarters
+    let lagging = lagging_sine_wave(1, 8, 1, 4);
+    let cosine = cosine_wave(1, 8);
+    assert_eq!(
+        lagging,
+        cosine
+            .iter()
+            .map(|value| -value + 0_f64)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(lagging[..3], [-1_f64, -cosine[1], 0_f64]);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        WaveSpec::new(0).generate(),
+        Err(Error::NotPositive("frequency"))
+    );
+    assert_eq!(
+        WaveSpec::new(441).with_repeats(0).generate(),
+        Err(Error::NotPositive("repeats"))
+    );
+    let error = WaveSpec::new(1_000).with_rate(100).generate().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "`frequency` should be less than `rate`, which is 100 Hz"
+    );
+    let spec = WaveSpec::new(10).with_rate(100);
+    let error = spec
+        .clone()
+        .with_modulation(Modulation::Fm {
+            modulator: 5,
+            deviation: 10,
+        })
+        .generate()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "`deviation` should be less than `frequency`, which is 10 Hz"
+    );
+    assert_eq!(
+        spec.clone()
+            .with_modulation(Modulation::Am {
+                modulator: 100,
+                depth: 50,
+            })
+            .check(),
+        Err(Error::AboveRate("modulator", 100))
+    );
+    assert_eq!(
+        spec.clone()
+            .with_modulation(Modulation::Fm {
+                modulator: 5,
+                deviation: 0,
+            })
+            .check(),
+        Err(Error::NotPositive("deviation"))
+    );
+    assert_eq!(spec.generate_int(65), Err(Error::InvalidBits(65)));
+    let error = WaveSpec::new(10)
+        .with_rate(10)
+        .generate_int(16)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "could not generate sine wave for `rate` of 10 Hz and `frequency` of 10 Hz"
+    );
+}
+
+/// Returns the values of the only item in `code` generated by `Generator`.
+fn parse_values<T: std::str::FromStr>(code: &str) -> Vec<T> {
+    let start = code.find("= [").unwrap() + 3;
+    let end = code.rfind("];").unwrap();
+    code[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().ok().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_codegen() {
+    let spec = WaveSpec::new(440).with_rate(48_000).with_repeats(3);
+    let generator = Generator::new().item(Item::new("BEEP", spec.clone()));
+    assert_eq!(
+        parse_values::<i16>(&generator.generate().unwrap()),
+        sine_wave!(frequency: 440, rate: 48_000, repeats: 3)
+    );
+    let generator = Generator::new().item(Item::new("BEEP", spec).with_type(SampleType::F32));
+    assert_eq!(
+        parse_values::<f32>(&generator.generate().unwrap()),
+        sine_wave!(frequency: 440, rate: 48_000, repeats: 3, type: f32)
+    );
+    let spec = WaveSpec::new(1_000).with_rate(44_100).with_skip(7);
+    let generator = Generator::new().item(Item::new("BEEP", spec).with_type(SampleType::F64));
+    assert_eq!(
+        parse_values::<f64>(&generator.generate().unwrap()),
+        sine_wave!(frequency: 1_000, rate: 44_100, skip: 7, type: f64)
+    );
+}
+
+#[test]
+fn test_c_header() {
+    let spec = WaveSpec::new(440).with_rate(48_000);
+    let generator = Generator::new().item(Item::new("BEEP", spec.clone()));
+    let header = generator.generate_c_header("BEEP_H").unwrap();
+    let start = header.find("= {").unwrap() + 3;
+    let end = header.rfind("};").unwrap();
+    let values: Vec<i16> = header[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().unwrap())
+        .collect();
+    assert_eq!(values, sine_wave!(frequency: 440, rate: 48_000));
+    assert!(header.contains("#define BEEP_FREQUENCY 440\n"));
+    assert!(header.contains("#define BEEP_RATE 48000\n"));
+    assert!(header.contains("#define BEEP_LEN 109\n"));
+    assert_eq!(parse_values::<i16>(&generator.generate().unwrap()), values);
+}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::biquad;
+
+fn main() {
+    let _biquad = biquad!(kind: lowpass, frequency: 1_000, type: f32, fraction: 8);
+}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::biquad;
+
+fn main() {
+    let _biquad = biquad!(kind: lowpass, rate: 8_000, frequency: 4_000);
+}
@@ -0,0 +1,12 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::sine_wave;
+
+const RATE: u32 = 48_000;
+
+fn main() {
+    let _wave = sine_wave!(frequency: 440, rate: RATE, modulation: am, modulator: 10);
+}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::sine_wave;
+
+fn main() {
+    let _wave = sine_wave!(frequency: 440, type: u16, pwm_top: 999, dead_time: 500);
+}
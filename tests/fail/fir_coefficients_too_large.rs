@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::fir;
+
+fn main() {
+    let _fir = fir!(kind: bandstop, rate: 8_000, low: 1_000, high: 3_000, taps: 3, window: rectangular);
+}
@@ -0,0 +1,12 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::fir;
+
+const RATE: u32 = 48_000;
+
+fn main() {
+    let _fir = fir!(kind: lowpass, rate: RATE, cutoff: 1_000, taps: 31);
+}
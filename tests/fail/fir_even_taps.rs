@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::fir;
+
+fn main() {
+    let _fir = fir!(kind: highpass, rate: 48_000, cutoff: 1_000, taps: 32);
+}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::goertzel;
+
+fn main() {
+    let _goertzel = goertzel!(block: 205, frequencies: []);
+}
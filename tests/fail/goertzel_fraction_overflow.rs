@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::goertzel;
+
+fn main() {
+    let _goertzel = goertzel!(rate: 44_100, block: 882, frequencies: [697], fraction: 15);
+}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::goertzel;
+
+fn main() {
+    let _goertzel = goertzel!(rate: 8_000, block: 205, frequencies: [697, 9_000]);
+}
@@ -0,0 +1,12 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::noise;
+
+noise! {
+    const NOISE = sine_wave(seed: 1, len: 10);
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::noise;
+
+fn main() {
+    let _noise = noise!(seed: 1, len: 10, frequency: 440);
+}
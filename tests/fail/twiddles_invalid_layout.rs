@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::twiddles;
+
+fn main() {
+    let _twiddles = twiddles!(size: 64, layout: complex);
+}
@@ -0,0 +1,10 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::window;
+
+fn main() {
+    let _window = window!(kind: kaiser, len: 10);
+}
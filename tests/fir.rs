@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::fir;
+
+fir! {
+    static STATIC_LOWPASS = fir(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7);
+}
+
+fir! {
+    pub const EXPORTED_LOWPASS = fir(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7);
+}
+
+const LOWPASS_8000_1000: [i16; 7] = [278, 2286, 8029, 11582, 8029, 2286, 278];
+
+#[test]
+fn test_lowpass() {
+    let lowpass = fir!(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7);
+    assert_eq!(lowpass, LOWPASS_8000_1000);
+    let lowpass =
+        fir!(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7, window: hamming, type: i16);
+    assert_eq!(lowpass, LOWPASS_8000_1000);
+    assert_eq!(STATIC_LOWPASS, LOWPASS_8000_1000);
+    assert_eq!(EXPORTED_LOWPASS, LOWPASS_8000_1000);
+}
+
+#[test]
+fn test_lowpass_f64() {
+    const LOWPASS_8000_1000: [f64; 7] = [
+        0.008485920061584358,
+        0.06975525008428168,
+        0.24503094177824827,
+        0.3534557761517712,
+        0.24503094177824838,
+        0.06975525008428168,
+        0.008485920061584358,
+    ];
+    let lowpass = fir!(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7, type: f64);
+    for (value, expected) in lowpass.iter().zip(LOWPASS_8000_1000) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+    assert!((lowpass.iter().sum::<f64>() - 1_f64).abs() < 1e-12);
+}
+
+#[test]
+fn test_highpass() {
+    const HIGHPASS_8000_1000: [i16; 7] = [-195, -1601, -5623, 24334, -5623, -1601, -195];
+    let highpass = fir!(kind: highpass, rate: 8_000, cutoff: 1_000, taps: 7);
+    assert_eq!(highpass, HIGHPASS_8000_1000);
+    // Unity gain at Nyquist frequency
+    let highpass = fir!(kind: highpass, rate: 8_000, cutoff: 1_000, taps: 31, type: f32);
+    let gain: f32 = highpass
+        .iter()
+        .enumerate()
+        .map(|(n, value)| {
+            if n.abs_diff(15) % 2 == 0 {
+                *value
+            } else {
+                -value
+            }
+        })
+        .sum();
+    assert!((gain - 1_f32).abs() < 1e-6);
+}
+
+#[test]
+fn test_bandpass() {
+    const BANDPASS_8000_1000_2000: [i8; 9] = [0, -10, -22, 20, 63, 20, -22, -10, 0];
+    let bandpass = fir!(kind: bandpass, rate: 8_000, low: 1_000, high: 2_000, taps: 9, type: i8);
+    assert_eq!(bandpass, BANDPASS_8000_1000_2000);
+}
+
+#[test]
+fn test_bandstop() {
+    let bandstop = fir!(kind: bandstop, rate: 8_000, low: 1_000, high: 2_000, taps: 63, window: kaiser, beta: 6, type: f64);
+    assert!((bandstop.iter().sum::<f64>() - 1_f64).abs() < 1e-12);
+    assert_eq!(bandstop.len(), 63);
+}
+
+#[test]
+fn test_fraction() {
+    // The center tap is one, which does not fit in Q15
+    let bandstop = fir!(kind: bandstop, rate: 8_000, low: 1_000, high: 3_000, taps: 3, window: rectangular, fraction: 14);
+    assert_eq!(bandstop, [0, 16384, 0]);
+    let lowpass = fir!(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7, type: i32, fraction: 16);
+    let reference = fir!(kind: lowpass, rate: 8_000, cutoff: 1_000, taps: 7, type: f64);
+    for (value, expected) in lowpass.into_iter().zip(reference) {
+        assert_eq!(value, (expected * 65_536_f64).round() as i32);
+    }
+}
+
+#[test]
+fn test_symmetric() {
+    let fir = fir!(kind: lowpass, rate: 48_000, cutoff: 4_000, taps: 64, window: blackman_harris, type: i32);
+    assert!(fir.iter().eq(fir.iter().rev()));
+}
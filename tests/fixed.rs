@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+#![cfg(feature = "fixed")]
+
+use fixed::types::{I1F15, I2F30, I8F8};
+use sine_macro::{biquad, fir, sine_wave};
+
+sine_wave! {
+    const CONST_FIXED = sine_wave(frequency: 100, rate: 400, type: I1F15);
+}
+
+const FIXED_100_400: [I1F15; 4] = [
+    I1F15::ZERO,
+    I1F15::MAX,
+    I1F15::ZERO,
+    I1F15::from_bits(-I1F15::MAX.to_bits()),
+];
+
+#[test]
+fn test_fixed() {
+    let wave = sine_wave!(frequency: 100, rate: 400, type: I1F15);
+    assert_eq!(wave, FIXED_100_400);
+    assert_eq!(CONST_FIXED, FIXED_100_400);
+}
+
+#[test]
+fn test_fixed_integer_bits() {
+    let wave = sine_wave!(frequency: 100, rate: 400, type: I8F8);
+    assert_eq!(wave, [I8F8::ZERO, I8F8::ONE, I8F8::ZERO, -I8F8::ONE]);
+    let wave = sine_wave!(frequency: 100, rate: 400, type: I8F8, full_scale: asymmetric);
+    assert_eq!(wave, [I8F8::ZERO, I8F8::ONE, I8F8::ZERO, -I8F8::ONE]);
+}
+
+#[test]
+fn test_fixed_bytes() {
+    let bytes = sine_wave!(frequency: 100, rate: 400, type: I8F8, bytes: le);
+    assert_eq!(bytes, [0, 0, 0, 1, 0, 0, 0, 255]);
+}
+
+#[test]
+fn test_fixed_coefficients() {
+    let fixed = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: I2F30);
+    let int = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: i32, fraction: 30);
+    assert_eq!(fixed.map(I2F30::to_bits), int);
+    let fixed = fir!(kind: lowpass, rate: 16_000, cutoff: 1_000, taps: 31, type: I1F15);
+    let int = fir!(kind: lowpass, rate: 16_000, cutoff: 1_000, taps: 31);
+    assert_eq!(fixed.map(I1F15::to_bits), int);
+}
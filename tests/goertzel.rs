@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::{goertzel, sine_wave};
+
+goertzel! {
+    static STATIC_DTMF = goertzel(rate: 44_100, block: 882, frequencies: [697, 770, 852, 941, 1209, 1336, 1477, 1633]);
+}
+
+goertzel! {
+    pub const EXPORTED_DTMF = goertzel(rate: 44_100, block: 882, frequencies: [697, 770, 852, 941, 1209, 1336, 1477, 1633]);
+}
+
+const DTMF_44100_882: [i16; 8] = [32605, 32581, 32528, 32468, 32250, 32164, 32023, 31867];
+
+/// Returns the power of the frequency bin with Goertzel algorithm.
+fn get_power(samples: &[f64], coefficient: f64) -> f64 {
+    let (mut s1, mut s2) = (0_f64, 0_f64);
+    for sample in samples {
+        let s0 = sample + coefficient * s1 - s2;
+        s2 = s1;
+        s1 = s0;
+    }
+    s1 * s1 + s2 * s2 - coefficient * s1 * s2
+}
+
+#[test]
+fn test_dtmf() {
+    let dtmf = goertzel!(
+        rate: 44_100,
+        block: 882,
+        frequencies: [697, 770, 852, 941, 1209, 1336, 1477, 1633],
+    );
+    assert_eq!(dtmf, DTMF_44100_882);
+    let dtmf = goertzel!(rate: 44_100, block: 882, frequencies: [697, 770, 852, 941, 1209, 1336, 1477, 1633], fraction: 14, type: i16);
+    assert_eq!(dtmf, DTMF_44100_882);
+    assert_eq!(STATIC_DTMF, DTMF_44100_882);
+    assert_eq!(EXPORTED_DTMF, DTMF_44100_882);
+}
+
+#[test]
+fn test_types() {
+    let [coefficient] = goertzel!(rate: 48_000, block: 1_090, frequencies: [440], type: i32);
+    assert_eq!(coefficient, 2143916786);
+    let [coefficient] =
+        goertzel!(rate: 48_000, block: 1_090, frequencies: [440], type: i8, fraction: 5);
+    assert_eq!(coefficient, 64);
+    let [coefficient] = goertzel!(rate: 48_000, block: 1_090, frequencies: [440], type: f64);
+    assert!((coefficient - 1.9966781005658332).abs() < 1e-12);
+}
+
+#[test]
+fn test_detect_generated_tone() {
+    const WAVE: [i16; 1_090] = sine_wave!(frequency: 440, rate: 48_000, len: 1_090);
+    let [tone, other] = goertzel!(rate: 48_000, block: 1_090, frequencies: [440, 1_000], type: f64);
+    let samples: Vec<_> = WAVE.iter().map(|sample| *sample as f64).collect();
+    let tone = get_power(&samples, tone);
+    let other = get_power(&samples, other);
+    assert!(tone > other * 1_000_f64);
+}
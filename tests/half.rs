@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+#![cfg(feature = "half")]
+
+use half::f16;
+use sine_macro::{biquad, sine_wave};
+
+sine_wave! {
+    static STATIC_F16 = sine_wave(frequency: 10, rate: 100, type: f16);
+}
+
+#[test]
+fn test_f16() {
+    let wave = sine_wave!(frequency: 10, rate: 100, type: f16);
+    let reference = sine_wave!(frequency: 10, rate: 100, type: f64);
+    assert_eq!(wave, reference.map(f16::from_f64));
+    assert_eq!(STATIC_F16, wave);
+    assert_eq!(wave[0], f16::ZERO);
+}
+
+#[test]
+fn test_f16_bytes() {
+    let bytes = sine_wave!(frequency: 100, rate: 400, type: f16, bytes: be);
+    assert_eq!(bytes, [0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0xbc, 0x00]);
+}
+
+#[test]
+fn test_f16_coefficients() {
+    let coefficients = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: f16);
+    let reference = biquad!(kind: lowpass, rate: 48_000, frequency: 1_000, type: f64);
+    assert_eq!(coefficients, reference.map(f16::from_f64));
+}
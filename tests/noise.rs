@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::noise;
+
+noise! {
+    static STATIC_NOISE = noise(seed: 1234, len: 10);
+}
+
+noise! {
+    pub const EXPORTED_NOISE = noise(seed: 1234, len: 10);
+}
+
+const WHITE_1234: [i16; 10] = [
+    15116, 6087, -19520, -12701, 14610, 12479, -307, -17849, -8854, 19785,
+];
+
+#[test]
+fn test_white() {
+    let noise = noise!(seed: 1234, len: 10);
+    assert_eq!(noise, WHITE_1234);
+    let noise = noise!(kind: white, len: 10, seed: 1234, type: i16);
+    assert_eq!(noise, WHITE_1234);
+}
+
+#[test]
+fn test_white_items() {
+    assert_eq!(STATIC_NOISE, WHITE_1234);
+    assert_eq!(EXPORTED_NOISE, WHITE_1234);
+}
+
+#[test]
+fn test_white_seeds() {
+    let noise = noise!(seed: 1235, len: 10);
+    assert_ne!(noise, WHITE_1234);
+}
+
+#[test]
+fn test_pink() {
+    const PINK_42: [i8; 16] = [
+        44, -36, -59, -62, -127, -14, -73, -1, -41, -14, -71, -53, -38, -33, 0, -67,
+    ];
+    let noise = noise!(seed: 42, len: 16, kind: pink, type: i8);
+    assert_eq!(noise, PINK_42);
+}
+
+#[test]
+fn test_lfsr() {
+    const LFSR_ACE1: [i8; 16] = [
+        127, -127, -127, -127, -127, 127, 127, 127, -127, -127, 127, 127, -127, 127, -127, 127,
+    ];
+    let noise = noise!(seed: 0xACE1, len: 16, kind: lfsr, type: i8);
+    assert_eq!(noise, LFSR_ACE1);
+}
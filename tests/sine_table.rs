@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::sine_wave;
+use sine_macro_const::{period, sample, sin_cos_turn, sine_table};
+
+/// Returns `len` samples of a wave starting from `skip` like `sine_wave!` with `skip`.
+fn skipped<const N: usize>(frequency: u32, rate: u32, skip: usize) -> [i16; N] {
+    let period = period(frequency, rate);
+    core::array::from_fn(|i| sample(i + skip, period))
+}
+
+#[test]
+fn test_period() {
+    assert_eq!(
+        period(441, 44100),
+        sine_wave!(frequency: 441, rate: 44100).len()
+    );
+    assert_eq!(period(441, 44100), 100);
+    assert_eq!(period(997, 48_000), 48);
+    assert_eq!(period(1, 1), 1);
+}
+
+#[test]
+fn test_sine_table() {
+    assert_eq!(
+        sine_table(441, 44100),
+        sine_wave!(frequency: 441, rate: 44100)
+    );
+    assert_eq!(sine_table(441, 44100), sine_wave!(frequency: 441));
+    assert_eq!(sine_table(100, 44100), sine_wave!(frequency: 100));
+    assert_eq!(
+        sine_table(997, 48_000),
+        sine_wave!(frequency: 997, rate: 48_000)
+    );
+    assert_eq!(
+        sine_table(50, 48_000),
+        sine_wave!(frequency: 50, rate: 48_000)
+    );
+    assert_eq!(sine_table(1, 44100), sine_wave!(frequency: 1, rate: 44100));
+    assert_eq!(sine_table(100, 700), sine_wave!(frequency: 100, rate: 700));
+}
+
+#[test]
+fn test_sine_table_len() {
+    assert_eq!(
+        sine_table(441, 44100),
+        sine_wave!(frequency: 441, rate: 44100, len: 10)
+    );
+    assert_eq!(
+        sine_table(10, 100),
+        sine_wave!(frequency: 10, rate: 100, repeats: 2)
+    );
+    assert_eq!(
+        sine_table(10, 100),
+        sine_wave!(frequency: 10, rate: 100, len: 20)
+    );
+    assert_eq!(
+        sine_table(10, 100),
+        sine_wave!(frequency: 10, rate: 100, len: 25)
+    );
+}
+
+#[test]
+fn test_sample_skip() {
+    assert_eq!(
+        skipped(10, 100, 5),
+        sine_wave!(frequency: 10, rate: 100, skip: 5)
+    );
+    assert_eq!(
+        skipped(10, 100, 15),
+        sine_wave!(frequency: 10, rate: 100, skip: 15)
+    );
+    assert_eq!(
+        skipped(441, 44100, 30),
+        sine_wave!(frequency: 441, rate: 44100, len: 80, skip: 30)
+    );
+}
+
+#[test]
+fn test_sample_all_periods() {
+    for period in 1..=5_000 {
+        let wave = sine_macro_core::sine_wave(1, period as u32);
+        for (i, value) in wave.into_iter().enumerate() {
+            assert_eq!(sample(i, period), (value * i16::MAX as f64) as i16);
+        }
+    }
+}
+
+#[test]
+fn test_sin_cos_turn() {
+    assert_eq!(sin_cos_turn(0, 4), (0_f64, 1_f64));
+    assert_eq!(sin_cos_turn(1, 4), (1_f64, 0_f64));
+    assert_eq!(sin_cos_turn(2, 4), (0_f64, -1_f64));
+    assert_eq!(sin_cos_turn(3, 4), (-1_f64, 0_f64));
+    assert_eq!(sin_cos_turn(7, 4), sin_cos_turn(3, 4));
+    let (sin, cos) = sin_cos_turn(1, 8);
+    assert!((sin - core::f64::consts::FRAC_1_SQRT_2).abs() <= f64::EPSILON);
+    assert_eq!(sin, cos);
+}
+
+#[test]
+fn test_const_context() {
+    const FREQUENCY: u32 = 1_000;
+    const RATE: u32 = 48_000;
+    const WAVE: [i16; period(FREQUENCY, RATE)] = sine_table(FREQUENCY, RATE);
+    sine_wave! {
+        const EXPECTED = sine_wave(frequency: FREQUENCY, rate: RATE);
+    }
+    assert_eq!(WAVE, EXPECTED);
+    assert_eq!(WAVE, sine_wave!(frequency: 1_000, rate: 48_000));
+}
+
+#[test]
+#[should_panic(expected = "`frequency` should be less than `rate`")]
+fn test_frequency_above_rate() {
+    let _ = sine_table::<1>(100, 10);
+}
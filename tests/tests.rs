@@ -4,6 +4,7 @@
  */
 
 use sine_macro::sine_wave;
+use std::f64::consts::{FRAC_PI_6, PI};
 
 const WAVE_100_10: [i16; 10] = [
     0, 19259, 31163, 31163, 19259, 0, -19259, -31163, -31163, -19259,
@@ -139,3 +140,416 @@ fn test_100_10_i32() {
     }
     assert_eq!(WAVE, WAVE_100_10);
 }
+
+#[test]
+fn test_100_10_am() {
+    const WAVE_100_10_AM: [i16; 20] = [
+        0, 15932, 27951, 29675, 19024, 0, -19024, -29675, -27951, -15932, 0, 12957, 18793, 17069,
+        9865, 0, -9865, -17069, -18793, -12957,
+    ];
+    let wave = sine_wave!(frequency: 10, rate: 100, modulation: am, modulator: 5, depth: 50);
+    assert_eq!(wave, WAVE_100_10_AM);
+}
+
+#[test]
+fn test_100_10_fm() {
+    const WAVE_100_10_FM: [i16; 20] = [
+        0, 26409, 31547, 14182, -10392, -27572, -32765, -28836, -20318, -10285, 0, 10285, 20318,
+        28836, 32765, 27572, 10392, -14182, -31547, -26409,
+    ];
+    let wave = sine_wave!(frequency: 10, rate: 100, modulation: fm, modulator: 5, deviation: 5);
+    assert_eq!(wave, WAVE_100_10_FM);
+}
+
+#[test]
+fn test_modulation_loop_length() {
+    let wave = sine_wave!(frequency: 440, rate: 48_000, modulation: am, modulator: 6);
+    assert_eq!(wave.len(), 24_000);
+    let wave = sine_wave!(frequency: 440, rate: 48_000, modulation: fm, modulator: 6, deviation: 40, repeats: 2);
+    assert_eq!(wave.len(), 48_000);
+}
+
+#[test]
+fn test_100_10_rounding() {
+    const WAVE_100_10_TRUNCATE: [i8; 10] = [0, 74, 120, 120, 74, 0, -74, -120, -120, -74];
+    const WAVE_100_10_NEAREST: [i8; 10] = [0, 75, 121, 121, 75, 0, -75, -121, -121, -75];
+    const WAVE_100_10_FLOOR: [i8; 10] = [0, 74, 120, 120, 74, 0, -75, -121, -121, -75];
+    const WAVE_100_10_CEIL: [i8; 10] = [0, 75, 121, 121, 75, 0, -74, -120, -120, -74];
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, rounding: truncate);
+    assert_eq!(wave, WAVE_100_10_TRUNCATE);
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, rounding: nearest);
+    assert_eq!(wave, WAVE_100_10_NEAREST);
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, rounding: floor);
+    assert_eq!(wave, WAVE_100_10_FLOOR);
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, rounding: ceil);
+    assert_eq!(wave, WAVE_100_10_CEIL);
+}
+
+#[test]
+fn test_100_10_dither() {
+    const WAVE_100_10_NEAREST: [i8; 10] = [-1, 75, 120, 121, 74, 0, -74, -120, -121, -74];
+    const WAVE_100_10_TRUNCATE: [i8; 10] = [0, 75, 120, 120, 74, 0, -73, -120, -120, -74];
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, rounding: nearest, dither: 7);
+    assert_eq!(wave, WAVE_100_10_NEAREST);
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, dither: 7);
+    assert_eq!(wave, WAVE_100_10_TRUNCATE);
+}
+
+#[test]
+fn test_100_10_asymmetric() {
+    const WAVE_100_10_I8: [i8; 10] = [0, 74, 120, 120, 74, 0, -75, -121, -121, -75];
+    const WAVE_100_10_I16: [i16; 10] = [
+        0, 19259, 31163, 31163, 19259, 0, -19260, -31164, -31164, -19260,
+    ];
+    const WAVE_100_10_I32: [i32; 10] = [
+        0,
+        1262259217,
+        2042378316,
+        2042378316,
+        1262259217,
+        0,
+        -1262259217,
+        -2042378317,
+        -2042378317,
+        -1262259217,
+    ];
+    const WAVE_100_10_I8_NEAREST: [i8; 10] = [0, 75, 121, 121, 75, 0, -75, -122, -122, -75];
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i8, full_scale: asymmetric);
+    assert_eq!(wave, WAVE_100_10_I8);
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i16, full_scale: asymmetric);
+    assert_eq!(wave, WAVE_100_10_I16);
+    let wave = sine_wave!(frequency: 10, rate: 100, type: i32, full_scale: asymmetric);
+    assert_eq!(wave, WAVE_100_10_I32);
+    let wave =
+        sine_wave!(frequency: 10, rate: 100, type: i8, full_scale: asymmetric, rounding: nearest);
+    assert_eq!(wave, WAVE_100_10_I8_NEAREST);
+}
+
+#[test]
+fn test_100_25_full_scale() {
+    let wave = sine_wave!(frequency: 25, rate: 100, type: i8, full_scale: symmetric);
+    assert_eq!(wave, [0, i8::MAX, 0, -i8::MAX]);
+    let wave = sine_wave!(frequency: 25, rate: 100, type: i8, full_scale: asymmetric);
+    assert_eq!(wave, [0, i8::MAX, 0, i8::MIN]);
+    let wave = sine_wave!(frequency: 25, rate: 100, type: i16, full_scale: asymmetric);
+    assert_eq!(wave, [0, i16::MAX, 0, i16::MIN]);
+    let wave = sine_wave!(frequency: 25, rate: 100, type: i32, full_scale: asymmetric);
+    assert_eq!(wave, [0, i32::MAX, 0, i32::MIN]);
+}
+
+#[test]
+fn test_100_10_f32() {
+    const WAVE_100_10: [f32; 10] = [
+        0.0,
+        0.58778524,
+        0.95105654,
+        0.95105654,
+        0.58778524,
+        0.0,
+        -0.58778524,
+        -0.95105654,
+        -0.95105654,
+        -0.58778524,
+    ];
+    let wave = sine_wave!(frequency: 10, rate: 100, type: f32);
+    for (value, expected) in wave.iter().zip(WAVE_100_10) {
+        assert!((value - expected).abs() < 1e-6);
+    }
+    let wave = sine_wave!(frequency: 25, rate: 100, type: f64);
+    assert_eq!(wave[1], 1_f64);
+    assert_eq!(wave[3], -1_f64);
+}
+
+sine_wave! {
+    static STATIC_QUADRATURE = sine_wave(frequency: 100, rate: 700, quadrature: pairs);
+}
+
+#[test]
+fn test_quadrature() {
+    const SINE_700_100: [i16; 7] = [0, 25618, 31945, 14217, -14217, -31945, -25618];
+    const COSINE_700_100: [i16; 7] = [32767, 20429, -7291, -29522, -29522, -7291, 20429];
+    let (sine, cosine) = sine_wave!(frequency: 100, rate: 700, quadrature: tables);
+    assert_eq!(sine, SINE_700_100);
+    assert_eq!(cosine, COSINE_700_100);
+    let pairs = sine_wave!(frequency: 100, rate: 700, quadrature: pairs);
+    assert!(
+        pairs
+            .into_iter()
+            .eq(SINE_700_100.into_iter().zip(COSINE_700_100))
+    );
+    assert_eq!(STATIC_QUADRATURE, pairs);
+    // Arguments apply to both tables
+    let (sine, cosine) =
+        sine_wave!(frequency: 100, rate: 700, quadrature: tables, len: 10, skip: 2);
+    assert_eq!(sine[..5], SINE_700_100[2..]);
+    assert_eq!(cosine[5..], COSINE_700_100[..5]);
+}
+
+#[test]
+fn test_quadrature_f64() {
+    let pairs = sine_wave!(frequency: 1_000, rate: 44_100, type: f64, quadrature: pairs);
+    assert_eq!(pairs.len(), 44);
+    for (sine, cosine) in pairs {
+        assert!((sine * sine + cosine * cosine - 1_f64).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_three_phases() {
+    const PHASE_A: [i16; 8] = [0, 23169, 32767, 23169, 0, -23169, -32767, -23169];
+    const PHASE_B: [i16; 8] = [-28377, -31650, -16383, 8480, 28377, 31650, 16383, -8480];
+    const PHASE_C: [i16; 8] = [28377, 8480, -16383, -31650, -28377, -8480, 16383, 31650];
+    let (a, b, c) = sine_wave!(frequency: 100, rate: 800, phases: 3);
+    assert_eq!(a, PHASE_A);
+    assert_eq!(b, PHASE_B);
+    assert_eq!(c, PHASE_C);
+    let frames = sine_wave!(frequency: 100, rate: 800, phases: 3, layout: frames);
+    for (index, frame) in frames.into_iter().enumerate() {
+        assert_eq!(frame, [PHASE_A[index], PHASE_B[index], PHASE_C[index]]);
+    }
+    let (a, b) = sine_wave!(frequency: 100, rate: 800, phases: 2, len: 4, skip: 2);
+    assert_eq!(a, PHASE_A[2..6]);
+    assert_eq!(b, [-32767, -23169, 0, 23169]);
+    // The first phase is the same wave as without phases
+    let (a, _, _) = sine_wave!(frequency: 440, rate: 44_100, type: f64, phases: 3);
+    assert_eq!(a, sine_wave!(frequency: 440, rate: 44_100, type: f64));
+}
+
+#[test]
+fn test_phase_shapes() {
+    for (a, b, c) in [
+        sine_wave!(frequency: 50, rate: 6_000, type: f64, phases: 3, shape: third_harmonic),
+        sine_wave!(frequency: 50, rate: 6_000, type: f64, phases: 3, shape: space_vector),
+    ] {
+        let peak = a.iter().fold(0_f64, |peak, value| peak.max(value.abs()));
+        assert!((peak - 1_f64).abs() < 1e-9);
+        // The zero sequence cancels out from line-to-line voltages, which stay sinusoidal
+        for (index, (a, b)) in a.into_iter().zip(b).enumerate() {
+            let angle = 2_f64 * PI * index as f64 / 120_f64 + FRAC_PI_6;
+            assert!((a - b - 2_f64 * angle.sin()).abs() < 1e-9);
+        }
+        assert!(c.iter().all(|value| value.abs() <= 1_f64 + 1e-12));
+    }
+}
+
+#[test]
+fn test_pwm_top() {
+    let compare = sine_wave!(frequency: 100, rate: 800, type: u16, pwm_top: 999, rounding: nearest);
+    assert_eq!(compare, [500, 853, 999, 853, 500, 146, 0, 146]);
+    let compare = sine_wave!(frequency: 100, rate: 800, type: u8, pwm_top: 255);
+    assert_eq!(compare.iter().max(), Some(&255));
+    assert_eq!(compare.iter().min(), Some(&0));
+    let frames = sine_wave!(
+        frequency: 100,
+        rate: 800,
+        type: u16,
+        pwm_top: 999,
+        rounding: nearest,
+        phases: 3,
+        layout: frames,
+    );
+    assert_eq!(frames[0][0], 500);
+    assert_eq!(frames[2][0], 999);
+}
+
+#[test]
+fn test_pwm_dead_time() {
+    let compare = sine_wave!(
+        frequency: 100,
+        rate: 800,
+        type: u16,
+        pwm_top: 999,
+        dead_time: 100,
+        rounding: nearest,
+    );
+    assert_eq!(compare, [500, 853, 899, 853, 500, 146, 100, 146]);
+    let compare = sine_wave!(frequency: 100, rate: 800, type: u8, pwm_top: 200, dead_time: 0);
+    assert_eq!(compare.iter().max(), Some(&200));
+    assert_eq!(compare.iter().min(), Some(&0));
+}
+
+sine_wave! {
+    static STATIC_PDM = sine_wave(frequency: 100, rate: 800, pdm: 4, type: u32);
+}
+
+#[test]
+fn test_pdm() {
+    assert_eq!(STATIC_PDM, [3086848002]);
+    let lsb = sine_wave!(frequency: 100, rate: 800, pdm: 4, type: u32, bit_order: lsb);
+    assert_eq!(lsb, [1074380781]);
+    assert_eq!(lsb[0].reverse_bits(), STATIC_PDM[0]);
+    // Bitstream of the sine wave has as many ones as zeros
+    let bitstream = sine_wave!(frequency: 1_000, rate: 8_000, pdm: 64, type: u8);
+    assert_eq!(bitstream.len(), 64);
+    let ones: u32 = bitstream.iter().map(|word| word.count_ones()).sum();
+    assert_eq!(ones, 256);
+    let words = sine_wave!(frequency: 100, rate: 800, pdm: 4, type: u16, repeats: 2, skip: 1);
+    assert_eq!(words.len(), 4);
+    assert_eq!(words[0], words[2]);
+}
+
+sine_wave! {
+    const CONST_BYTES = sine_wave(frequency: 100, rate: 800, type: i32, bytes: be);
+}
+
+#[test]
+fn test_bytes() {
+    const WAVE: [i16; 8] = [0, 23169, 32767, 23169, 0, -23169, -32767, -23169];
+    let le = sine_wave!(frequency: 100, rate: 800, bytes: le);
+    let be = sine_wave!(frequency: 100, rate: 800, bytes: be);
+    assert_eq!(le.len(), 16);
+    for (index, value) in WAVE.into_iter().enumerate() {
+        assert_eq!(le[index * 2..][..2], value.to_le_bytes());
+        assert_eq!(be[index * 2..][..2], value.to_be_bytes());
+    }
+    let wave = sine_wave!(frequency: 100, rate: 800, type: i32);
+    assert_eq!(CONST_BYTES.len(), 32);
+    assert!(
+        CONST_BYTES
+            .chunks(4)
+            .map(|chunk| i32::from_be_bytes(chunk.try_into().unwrap()))
+            .eq(wave)
+    );
+    let floats = sine_wave!(frequency: 100, rate: 800, type: f32, bytes: le);
+    assert_eq!(floats[8..12], 1_f32.to_le_bytes());
+    let compare = sine_wave!(frequency: 100, rate: 800, type: u16, pwm_top: 999, bytes: le);
+    assert_eq!(compare[4..6], 999_u16.to_le_bytes());
+}
+
+#[test]
+fn test_bytes_interleaved() {
+    let frames = sine_wave!(frequency: 100, rate: 800, type: i8, phases: 3, layout: frames);
+    let bytes = sine_wave!(
+        frequency: 100,
+        rate: 800,
+        type: i8,
+        phases: 3,
+        layout: frames,
+        bytes: le,
+    );
+    assert!(
+        bytes
+            .into_iter()
+            .eq(frames.into_iter().flatten().map(|value| value as u8))
+    );
+    let (a, b) = sine_wave!(frequency: 100, rate: 800, type: i8, phases: 2, bytes: le);
+    assert_eq!(a.len(), 8);
+    assert_eq!(a[0], b[4]);
+    let pdm = sine_wave!(frequency: 100, rate: 800, pdm: 4, type: u32, bytes: be);
+    assert_eq!(pdm, 3086848002_u32.to_be_bytes());
+}
+
+#[test]
+fn test_i24() {
+    const WAVE_I24: [i32; 8] = [
+        0, 5931640, 8388607, 5931640, 0, -5931640, -8388607, -5931640,
+    ];
+    let wave = sine_wave!(frequency: 100, rate: 800, type: i24);
+    assert_eq!(wave, WAVE_I24);
+    let right = sine_wave!(frequency: 100, rate: 800, type: i24, justify: right);
+    assert_eq!(right, WAVE_I24);
+    let left = sine_wave!(frequency: 100, rate: 800, type: i24, justify: left);
+    assert!(left.into_iter().eq(WAVE_I24.map(|value| value << 8)));
+    let wave = sine_wave!(frequency: 100, rate: 800, type: i24, full_scale: asymmetric);
+    assert_eq!(wave[6], -8388608);
+}
+
+#[test]
+fn test_i24_bytes() {
+    let packed = sine_wave!(frequency: 100, rate: 800, type: i24, bytes: le);
+    assert_eq!(packed.len(), 24);
+    assert_eq!(packed[6..9], [0xff, 0xff, 0x7f]);
+    assert_eq!(packed[18..21], [0x01, 0x00, 0x80]);
+    let packed = sine_wave!(frequency: 100, rate: 800, type: i24, bytes: be);
+    assert_eq!(packed[6..9], [0x7f, 0xff, 0xff]);
+    let left = sine_wave!(frequency: 100, rate: 800, type: i24, justify: left, bytes: le);
+    assert_eq!(left.len(), 32);
+    assert_eq!(left[8..12], (8388607_i32 << 8).to_le_bytes());
+}
+
+#[test]
+fn test_i64_f64_reference() {
+    let reference = sine_wave!(frequency: 1_000, rate: 44_100, type: f64);
+    let wave = sine_wave!(frequency: 1_000, rate: 44_100, type: i64);
+    assert_eq!(wave.len(), reference.len());
+    for (value, expected) in wave.into_iter().zip(reference) {
+        let expected = expected * i64::MAX as f64;
+        assert!((value as f64 - expected).abs() <= expected.abs() * f64::EPSILON + 1_f64);
+    }
+    // Peaks are clamped to the range of the type even though `i64::MAX` rounds up in `f64`
+    let wave = sine_wave!(frequency: 100, rate: 400, type: i64);
+    assert_eq!(wave[1], i64::MAX);
+    assert_eq!(wave[3], -i64::MAX);
+    let wave = sine_wave!(frequency: 100, rate: 400, type: i64, full_scale: asymmetric);
+    assert_eq!(wave[3], i64::MIN);
+}
+
+#[test]
+fn test_i128_f64_reference() {
+    let reference = sine_wave!(frequency: 1_000, rate: 44_100, type: f64);
+    let wave = sine_wave!(frequency: 1_000, rate: 44_100, type: i128);
+    for (value, expected) in wave.into_iter().zip(reference) {
+        let expected = expected * i128::MAX as f64;
+        assert!((value as f64 - expected).abs() <= expected.abs() * f64::EPSILON);
+    }
+    let wave = sine_wave!(frequency: 100, rate: 400, type: i128, rounding: nearest);
+    assert_eq!(wave[1], i128::MAX);
+    assert_eq!(wave[3], -i128::MAX);
+    let bytes = sine_wave!(frequency: 100, rate: 400, type: i128, bytes: le);
+    assert_eq!(bytes[16..32], i128::MAX.to_le_bytes());
+    let wave = sine_wave!(frequency: 100, rate: 400, type: i128, full_scale: asymmetric);
+    assert_eq!(wave[1], i128::MAX);
+    assert_eq!(wave[3], i128::MIN);
+}
+
+mod samples {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Sample(pub i16);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Volts(f32);
+
+    impl Volts {
+        pub const fn from_unit(value: f32) -> Self {
+            Self(value * 3.3)
+        }
+    }
+
+    pub fn from_millivolts(value: i16) -> Volts {
+        Volts(value as f32 / 1_000_f32)
+    }
+}
+
+use samples::{Sample, Volts};
+
+sine_wave! {
+    const CONST_SAMPLES = sine_wave(frequency: 100, rate: 400, type: Sample, storage: i16);
+}
+
+#[test]
+fn test_custom_type() {
+    let expected = [Sample(0), Sample(32767), Sample(0), Sample(-32767)];
+    let wave = sine_wave!(frequency: 100, rate: 400, type: samples::Sample, storage: i16);
+    assert_eq!(wave, expected);
+    assert_eq!(CONST_SAMPLES, expected);
+    let wave =
+        sine_wave!(frequency: 100, rate: 400, type: Volts, storage: f32, wrap: Volts::from_unit);
+    let reference = sine_wave!(frequency: 100, rate: 400, type: f32);
+    assert_eq!(wave, reference.map(Volts::from_unit));
+    let wave = sine_wave!(
+        frequency: 100,
+        rate: 400,
+        type: Volts,
+        storage: i16,
+        wrap: samples::from_millivolts
+    );
+    let reference = sine_wave!(frequency: 100, rate: 400);
+    assert_eq!(wave, reference.map(samples::from_millivolts));
+    let wave = sine_wave!(frequency: 100, rate: 400, type: Sample, storage: i16, wrap: wrap);
+    assert_eq!(wave, reference.map(wrap));
+}
+
+/// A function that is not `const fn` and can be used only outside of const context.
+fn wrap(value: i16) -> Sample {
+    Sample(value)
+}
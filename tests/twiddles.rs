@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::{sine_wave, twiddles};
+
+twiddles! {
+    static STATIC_FFT_8 = twiddles(size: 8);
+}
+
+twiddles! {
+    pub const EXPORTED_FFT_8 = twiddles(size: 8, type: i16, layout: interleaved);
+}
+
+const TWIDDLES_8: [i16; 8] = [32767, 0, 23170, -23170, 0, -32767, -23170, -23170];
+const BIT_REVERSAL_8: [u8; 8] = [0, 4, 2, 6, 1, 5, 3, 7];
+
+#[test]
+fn test_interleaved() {
+    let (twiddles, bit_reversal) = twiddles!(size: 8);
+    assert_eq!(twiddles, TWIDDLES_8);
+    assert_eq!(bit_reversal, BIT_REVERSAL_8);
+    assert_eq!(STATIC_FFT_8, (TWIDDLES_8, BIT_REVERSAL_8));
+    assert_eq!(EXPORTED_FFT_8, (TWIDDLES_8, BIT_REVERSAL_8));
+}
+
+#[test]
+fn test_pairs() {
+    let (twiddles, bit_reversal) = twiddles!(size: 8, layout: pairs, type: i8);
+    assert_eq!(twiddles, [(127, 0), (90, -90), (0, -127), (-90, -90)]);
+    assert_eq!(bit_reversal, BIT_REVERSAL_8);
+}
+
+#[test]
+fn test_index_types() {
+    let (_, bit_reversal): (_, [u8; 256]) = twiddles!(size: 256);
+    assert_eq!(bit_reversal[1], 128);
+    assert_eq!(bit_reversal[255], 255);
+    let (_, bit_reversal): (_, [u16; 512]) = twiddles!(size: 512);
+    assert_eq!(bit_reversal[1], 256);
+    assert_eq!(bit_reversal[3], 384);
+}
+
+#[test]
+fn test_fft() {
+    const N: usize = 64;
+    let (twiddles, bit_reversal) = twiddles!(size: 64, layout: pairs, type: f64);
+    let wave = sine_wave!(frequency: 4, rate: 64, len: 64, type: f64);
+    let mut data: Vec<(f64, f64)> = bit_reversal
+        .iter()
+        .map(|index| (wave[*index as usize], 0_f64))
+        .collect();
+    let mut len = 2;
+    while len <= N {
+        for start in (0..N).step_by(len) {
+            for k in 0..len / 2 {
+                let (w_re, w_im) = twiddles[k * N / len];
+                let (a_re, a_im) = data[start + k];
+                let (b_re, b_im) = data[start + k + len / 2];
+                let (t_re, t_im) = (b_re * w_re - b_im * w_im, b_re * w_im + b_im * w_re);
+                data[start + k] = (a_re + t_re, a_im + t_im);
+                data[start + k + len / 2] = (a_re - t_re, a_im - t_im);
+            }
+        }
+        len *= 2;
+    }
+    for (bin, (re, im)) in data.iter().enumerate() {
+        let magnitude = (re * re + im * im).sqrt();
+        if bin == 4 || bin == N - 4 {
+            assert!((magnitude - 32_f64).abs() < 1e-9);
+            // Sine has negative imaginary part at the positive frequency
+            assert_eq!(*im < 0_f64, bin == 4);
+        } else {
+            assert!(magnitude < 1e-9);
+        }
+    }
+}
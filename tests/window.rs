@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) 2025 Tomi Leppänen
+ * SPDX-License-Identifier: MIT
+ */
+
+use sine_macro::window;
+
+window! {
+    static STATIC_WINDOW = window(kind: hann, len: 8);
+}
+
+window! {
+    pub const EXPORTED_WINDOW = window(kind: hann, len: 8);
+}
+
+const HANN_8: [i16; 8] = [0, 6168, 20029, 31144, 31144, 20029, 6168, 0];
+
+#[test]
+fn test_hann() {
+    let window = window!(kind: hann, len: 8);
+    assert_eq!(window, HANN_8);
+    let window = window!(len: 8, kind: hann, symmetry: symmetric, type: i16);
+    assert_eq!(window, HANN_8);
+    assert_eq!(STATIC_WINDOW, HANN_8);
+    assert_eq!(EXPORTED_WINDOW, HANN_8);
+}
+
+#[test]
+fn test_hann_periodic() {
+    const HANN_8_PERIODIC: [i16; 8] = [0, 4798, 16383, 27968, 32767, 27968, 16383, 4798];
+    let window = window!(kind: hann, len: 8, symmetry: periodic);
+    assert_eq!(window, HANN_8_PERIODIC);
+}
+
+#[test]
+fn test_hamming() {
+    const HAMMING_8: [i16; 8] = [2621, 8296, 21048, 31274, 31274, 21048, 8296, 2621];
+    let window = window!(kind: hamming, len: 8);
+    assert_eq!(window, HAMMING_8);
+}
+
+#[test]
+fn test_blackman() {
+    const BLACKMAN_8: [i16; 8] = [0, 2963, 15046, 30157, 30157, 15046, 2963, 0];
+    let window = window!(kind: blackman, len: 8);
+    assert_eq!(window, BLACKMAN_8);
+}
+
+#[test]
+fn test_blackman_harris() {
+    const BLACKMAN_HARRIS_8: [i16; 8] = [1, 1094, 10905, 29141, 29141, 10905, 1094, 1];
+    let window = window!(kind: blackman_harris, len: 8);
+    assert_eq!(window, BLACKMAN_HARRIS_8);
+}
+
+#[test]
+fn test_flat_top() {
+    const FLAT_TOP_9: [i16; 9] = [-13, -880, -1793, 14552, 32767, 14552, -1793, -880, -13];
+    let window = window!(kind: flat_top, len: 9);
+    assert_eq!(window, FLAT_TOP_9);
+}
+
+#[test]
+fn test_kaiser() {
+    const KAISER_8_5: [i16; 8] = [1202, 8869, 21355, 31300, 31300, 21355, 8869, 1202];
+    const KAISER_8_8_6: [i8; 8] = [0, 11, 58, 116, 116, 58, 11, 0];
+    let window = window!(kind: kaiser, beta: 5, len: 8);
+    assert_eq!(window, KAISER_8_5);
+    let window = window!(kind: kaiser, beta: 5.0, len: 8);
+    assert_eq!(window, KAISER_8_5);
+    let window = window!(kind: kaiser, beta: 8.6, len: 8, type: i8);
+    assert_eq!(window, KAISER_8_8_6);
+}
+
+#[test]
+fn test_rectangular() {
+    let window = window!(kind: rectangular, len: 4, type: i8);
+    assert_eq!(window, [i8::MAX; 4]);
+    let window = window!(kind: hann, len: 1, type: i8);
+    assert_eq!(window, [i8::MAX]);
+}